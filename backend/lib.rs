@@ -1,6 +1,6 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Env, Map, Symbol, Vec,
+    contract, contractclient, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
     token::Client as TokenClient,
     unwrap::UnwrapInfallible,
 };
@@ -20,6 +20,42 @@ pub enum Error {
     CycleNotReady = 9,
     CycleNotPassed = 10,
     Paused = 11,
+    DepositNotRequired = 12,
+    InvalidConfig = 13,
+    MemberBanned = 14,
+    NothingToClaim = 15,
+    FineOwed = 16,
+    InsufficientFunds = 17,
+    CircleFinished = 18,
+    NotFinished = 19,
+    TokenNotAccepted = 20,
+    DisputeWindowEnabled = 21,
+    CyclePending = 22,
+    NoPendingCycle = 23,
+    DisputeWindowOpen = 24,
+    NothingToRefund = 25,
+    Overflow = 26,
+    NotInvited = 27,
+    InvalidAmount = 28,
+    NoFineOwed = 29,
+    QuorumNotMet = 30,
+    CircleFull = 31,
+    CannotChangeToken = 32,
+    InvalidNonce = 33,
+    AlreadyVoted = 34,
+    DepositWindowClosed = 35,
+    OwnerCannotJoin = 36,
+    DepositsPaused = 37,
+    CycleAlreadyStarted = 38,
+    NotYetOpen = 39,
+    ClaimsOutstanding = 40,
+    InvalidToken = 41,
+    AlreadyPaidOut = 42,
+    StaleSnapshot = 43,
+    AlreadyMigrated = 44,
+    UnreadableState = 45,
+    RoundInProgress = 46,
+    OrderNotDrawn = 47,
 }
 
 // --- Contract Data Keys ---
@@ -29,55 +65,261 @@ pub enum DataKey {
     CircleState,    // Global state (CircleState)
     MemberRep(Address), // Member's reputation and state (MemberState)
     LastCycleTime,  // u64 timestamp of the last executed cycle
+    PendingCycle,   // Cycle awaiting finalization in two-phase execution (PendingCycle)
+    CycleLog(u32, u32), // Record of an executed cycle, keyed by season and cycle number (CycleRecord)
+    DepositNonce(Address), // Next nonce expected by `deposit_signed` for a member (u64)
+    MemberContact(Address), // Opt-in notification handle for off-chain notifiers (Bytes)
+    Version,        // CONTRACT_VERSION the stored state was written by (u32)
+    PenaltyPoolTotal, // Sum of all positive member balances awaiting claim (i128)
+    Receipt(Address, u32, u32), // Proof that a member deposited for a season's cycle (bool)
+    Stats,          // Lifetime totals across all executed cycles (CircleStats)
+    PrepaidTotal,   // `token_asset` held for prepaid cycles and prepaid balances (i128)
+    StartingReputation, // Copy of `config.starting_reputation`, so member reads skip the circle state (u32)
+    FineCreditors(Address), // Recipients owed this member's unpaid `ToRecipient` fines, oldest first (Vec<(Address, i128)>)
+    BackedCredit,   // `token_asset` actually held against member credits: collected fines and yield (i128)
 }
 
+// --- Constants ---
+pub const CONTRACT_VERSION: u32 = 2; // Bump on every release that changes behaviour or storage
+const MAX_BPS: u32 = 10_000;
+const MAX_PAGE_SIZE: u32 = 25; // Upper bound on entries returned by paginated views
+const RATE_SCALE: i128 = 10_000; // Token rate meaning 1 unit of token per 1 unit of `token_asset`
+const STREAK_BONUS_INTERVAL: u32 = 5; // Bonus reputation point every N consecutive deposits
+const MAX_REPUTATION_DELTA: u32 = 100; // Upper bound on configured reputation gain/loss
+const DEFAULT_STARTING_REPUTATION: u32 = 10;
+const MAX_MEMBERS: u32 = u64::BITS; // One bit per member in the u64 bitmaps
+const MEMBER_STATE_VERSION: u32 = 4; // Bump when `MemberState` changes shape, and teach `migrate_member` the old one
+
 // --- State Structs ---
 
 #[contracttype]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayoutOrder {
+    Sequential, // Members are paid in join order
+    Random,     // Members are paid in a shuffled order fixed at the first execution
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PenaltyPolicy {
+    Redistribute, // Pooled penalties are credited equally to every member
+    ToOwner,      // Pooled penalties are credited to the owner's claimable balance
+    Burn,         // Pooled penalties are credited to nobody and stay locked in the contract
+    ToRecipient,  // Paid penalties are added to the current recipient's payout; unpaid ones follow as repaid
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CircleStatus {
+    Forming,  // No cycle has executed yet this season; members can still join
+    Active,   // Cycles are running
+    Paused,   // Halted by the owner, a member vote, auto-pause, or a deposits-only pause
+    Finished, // All `max_rounds` rounds have completed
+}
+
+#[contracttype]
+#[derive(Clone)]
 pub struct CircleConfig {
     pub owner: Address,
     pub token_asset: Address,
     pub deposit_amount: i128,
     pub cycle_interval_secs: u64, // Time interval between cycle executions
     pub join_deadline_secs: u64,  // Max time for joining after creation
+    pub excuse_paid_members: bool, // Members already paid out this round skip further deposits
+    pub fee_bps: u32, // Protocol fee taken from each payout, in basis points
+    pub fee_recipient: Address, // Receives the protocol fee
+    pub min_reputation: u32, // Members whose score drops below this are banned (0 = no floor)
+    pub max_rounds: u32, // Rotation rounds before the circle finishes (0 = runs indefinitely)
+    pub payout_order: PayoutOrder,
+    pub accepted_tokens: Vec<Address>, // Deposit token whitelist, always includes `token_asset`
+    pub token_rates: Map<Address, i128>, // Token units per `token_asset` unit, scaled by RATE_SCALE
+    pub yield_contract: Option<Address>, // Adapter holding pooled `token_asset` deposits between payouts
+    pub dispute_window_secs: u64, // Delay between begin_cycle and finalize_cycle (0 = single-phase execute_cycle)
+    pub rep_gain: u32, // Reputation gained per successful deposit
+    pub rep_loss: u32, // Reputation lost per missed deposit
+    pub starting_reputation: u32, // Reputation score given to members with no stored state
+    pub allowlist: Vec<Address>, // Addresses invited to join, seeded from `create_circle`'s members
+    pub min_deposits_for_payout: u32, // Deposits needed before a cycle pays out (0 = no quorum)
+    pub skip_defaulting_recipient: bool, // Pass over an unpaid recipient who missed this cycle's deposit
+    pub penalty_policy: PenaltyPolicy,
+    pub reputation_decay_per_cycle: u32, // Extra reputation lost per missed cycle once inactive (0 = off)
+    pub inactivity_gap_cycles: u32, // Cycles without a deposit before decay applies
+    pub join_fee: i128, // One-time, non-refundable fee paid to the owner on `join_circle`
+    pub pause_quorum: u32, // Member votes that pause the circle without the owner (0 = disabled)
+    pub deposit_window_secs: u64, // How long into a cycle deposits are accepted (0 = until execution)
+    pub owner_is_member: bool, // Whether the owner may also join as a contributing member
+    pub max_fine_per_member: i128, // Floor on a member's balance is -max_fine_per_member (0 = uncapped)
+    pub round_robin_dust: bool, // Give the redistribution remainder to a rotating member instead of carrying it
+    pub priority_window_secs: u64, // Opening stretch of each cycle reserved for high-reputation depositors (0 = off)
+    pub priority_min_reputation: u32, // Reputation needed to deposit during the priority window
+    pub bonus_skim_bps: u32, // Share of each pot set aside for the final recipient of a finite circle
+    pub max_failures_before_pause: u32, // Consecutive quorum failures that pause the circle (0 = disabled)
+    pub token_decimals: u32, // `token_asset`'s decimals, included in amount-carrying events
+    pub late_after_secs: u64, // How long into a cycle deposits count as on time
+    pub late_grace_period_secs: u64, // Lateness at which the late fine reaches its full amount (0 = no late fines)
+    pub first_cycle_delay_secs: u64, // Wait after creation or restart before the first cycle can run (0 = immediately)
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub struct CircleState {
     pub config: CircleConfig,
-    pub members: Vec<Address>, // The final, confirmed member list
-    pub member_deposits: Map<Address, u64>, // Temporary pre-confirmed members
+    pub slots: Map<u32, Option<Address>>, // Confirmed members by slot; a departed member leaves an empty slot
+    pub member_deposits: Map<Address, u64>, // When each member deposited this cycle, for the late tier
     pub current_cycle: u32,
-    pub next_payout_index: u32, // Index in `members` vector for the next payout
-    pub deposits_bitmap: u32,  // Bitmap for current cycle deposits (1 = deposited, 0 = missed/late)
+    pub next_payout_index: u32, // Rotation position (over `slots`) of the next payout
+    pub deposits_bitmap: u64,  // Bitmap for current cycle deposits by slot (1 = deposited, 0 = missed/late)
     pub is_paused: bool,
     pub is_open_for_joining: bool,
+    pub completed_rounds: u32, // Full rotations completed since creation/restart
+    pub is_finished: bool, // Set once `max_rounds` rotations have completed
+    pub payout_sequence: Vec<u32>, // Shuffled slots used by `PayoutOrder::Random`
+    pub cycle_deposits: Map<Address, DepositRecord>, // What each member deposited this cycle
+    pub last_executed_cycle: u32, // Cycle number of the most recent execution (0 = none yet)
+    pub yield_principal: i128, // Deposits currently parked in the yield adapter
+    pub departed_deposits: Map<Address, DepositRecord>, // Unspent deposits of members who left
+    pub paid_this_round: u64, // Bitmap of slots already paid in the current rotation round
+    pub joining_opened_at: u64, // Start of the join window (creation or restart time)
+    pub current_cycle_deposited: i128, // Deposits held for the current cycle, valued in `token_asset`
+    pub penalty_dust: i128, // Redistribution remainder carried into the next cycle's penalty pool
+    pub pause_votes: Vec<Address>, // Members who voted to pause since the last unpause
+    pub dust_recipient_index: u32, // Member who receives the next remainder under `round_robin_dust`
+    pub deposits_paused: bool, // Halts deposits and cycle execution while claims keep working
+    pub bonus_reserve: i128, // Skimmed `token_asset` awaiting the final payout under `bonus_skim_bps`
+    pub consecutive_failures: u32, // Quorum failures since the last successful cycle or unpause
+    pub last_failure_time: u64, // When the last failure was counted (0 = none)
+    pub waitlist: Vec<Address>, // Addresses that take vacated slots, first come first served, before the first cycle
+    pub season: u32, // Run of the circle, bumped by `restart_circle` so per-cycle records don't collide
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct DepositRecord {
+    pub token: Address,
+    pub amount: i128, // In units of `token`
+    pub payer: Option<Address>, // Who paid when it wasn't the member (e.g. `cover_missing`); refunds go there
+    pub prior_deposit_cycle: u32, // Member's `last_deposit_cycle` before this deposit, restored on refund
+    pub prior_best_streak: u32, // Member's `best_streak` before this deposit, restored on refund
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleRecord {
+    pub recipient: Address,
+    pub pot: i128, // Gross `token_asset` pot, before the protocol fee
+    pub num_deposited: u32,
+    pub num_missed: u32, // Members penalized for missing the deposit
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircleStats {
+    pub total_pot_distributed: i128, // Net `token_asset` paid to recipients, after fees
+    pub total_penalties_collected: i128, // Missed and late fines levied
+    pub total_cycles_executed: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct PendingCycle {
+    pub cycle: u32,
+    pub opened_at: u64, // When the dispute window opened
+    pub deposits_bitmap: u64, // Deposits snapshotted at begin_cycle
+    pub members: Vec<Address>, // Roster snapshotted at begin_cycle
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub struct MemberState {
-    pub reputation_score: u32, // +1 for success, -1 for missed
+    pub reputation_score: u32, // +rep_gain for success, -rep_loss for missed
     pub penalties_accrued: i128, // Total value of penalties owed to the member
     pub last_deposit_cycle: u32, // Last cycle member successfully deposited for
+    pub received_payout: bool, // Already received the pot in the current rotation round
+    pub banned: bool, // Fell below the circle's reputation floor
+    pub current_streak: u32, // Consecutive cycles deposited, reset on a miss
+    pub best_streak: u32, // Longest streak ever reached
+    pub deposited_count: u32, // Cycles the member deposited for
+    pub missed_count: u32, // Cycles the member was penalized for missing
+    pub prepaid_cycles: u32, // Future deposits paid up front, applied at execution if not deposited
+    pub opt_out_penalty_share: bool, // Forgo redistributed penalties, leaving them to the other members
+    pub payout_address: Option<Address>, // Where the member's pot is sent (their own address if unset)
+    pub prepaid_balance: i128, // Funds held for auto-deposits, drawn at execution if not deposited
+    pub version: u32, // MEMBER_STATE_VERSION the entry was written with
+}
+
+// Member entry before `prepaid_balance` was added (version 3). Upgraded on read.
+#[contracttype]
+#[derive(Clone)]
+pub struct MemberStateV3 {
+    pub reputation_score: u32,
+    pub penalties_accrued: i128,
+    pub last_deposit_cycle: u32,
+    pub received_payout: bool,
+    pub banned: bool,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub deposited_count: u32,
+    pub missed_count: u32,
+    pub prepaid_cycles: u32,
+    pub opt_out_penalty_share: bool,
+    pub payout_address: Option<Address>,
+    pub version: u32,
+}
+
+// Member entry before `payout_address` was added (version 2). Upgraded on read.
+#[contracttype]
+#[derive(Clone)]
+pub struct MemberStateV2 {
+    pub reputation_score: u32,
+    pub penalties_accrued: i128,
+    pub last_deposit_cycle: u32,
+    pub received_payout: bool,
+    pub banned: bool,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub deposited_count: u32,
+    pub missed_count: u32,
+    pub prepaid_cycles: u32,
+    pub opt_out_penalty_share: bool,
+    pub version: u32,
+}
+
+// The original, unversioned member entry. Entries in this shape are upgraded on read.
+#[contracttype]
+#[derive(Clone)]
+pub struct MemberStateV1 {
+    pub reputation_score: u32,
+    pub penalties_accrued: i128,
+    pub last_deposit_cycle: u32,
+}
+
+// --- External Interfaces ---
+
+/// Minimal interface a yield adapter must implement to hold pooled deposits between payouts.
+#[contractclient(name = "YieldClient")]
+pub trait YieldAdapter {
+    /// Credits `from` with `amount` of the circle's token, already transferred to the adapter.
+    fn deposit(env: Env, from: Address, amount: i128);
+
+    /// Sends everything held for `to` (principal plus yield) back to it and returns the amount.
+    fn withdraw(env: Env, to: Address) -> i128;
 }
 
 // --- Events ---
 #[contractimpl]
 impl CircleState {
     fn emit_deposit_event(env: &Env, member: Address, cycle: u32) {
-        env.events().publish((Symbol::new(env, "deposit"), member), cycle);
+        env.events().publish((Symbol::new(env, "deposit"), member, cycle), cycle);
     }
     
-    fn emit_payout_event(env: &Env, recipient: Address, cycle: u32, amount: i128) {
-        env.events().publish((Symbol::new(env, "payout"), recipient), (cycle, amount));
+    fn emit_payout_event(env: &Env, recipient: Address, cycle: u32, gross_pot: i128, net_payout: i128, num_depositors: u32, decimals: u32) {
+        env.events().publish((Symbol::new(env, "payout"), recipient, cycle), (cycle, gross_pot, net_payout, num_depositors, decimals));
     }
 
-    fn emit_penalty_event(env: &Env, member: Address, cycle: u32, amount: i128, is_late: bool) {
+    fn emit_penalty_event(env: &Env, member: Address, cycle: u32, amount: i128, is_late: bool, decimals: u32) {
         let ty = if is_late { symbol_short!("late") } else { symbol_short!("missed") };
-        env.events().publish((Symbol::new(env, "penalty"), member, ty), (cycle, amount));
+        env.events().publish((Symbol::new(env, "penalty"), member, ty, cycle), (cycle, amount, decimals));
     }
 
     fn emit_cycle_executed_event(env: &Env, cycle: u32, recipient: Address) {
@@ -87,21 +329,91 @@ impl CircleState {
     fn emit_member_joined_event(env: &Env, member: Address) {
         env.events().publish((Symbol::new(env, "joined"), member), ());
     }
+
+    fn emit_member_left_event(env: &Env, member: Address, cycle: u32) {
+        env.events().publish((Symbol::new(env, "left"), member), cycle);
+    }
+
+    fn emit_member_removed_event(env: &Env, member: Address, by_owner: Address, cycle: u32) {
+        env.events().publish((Symbol::new(env, "removed"), member), (by_owner, cycle));
+    }
+
+    fn emit_deposit_covered_event(env: &Env, member: Address, owner: Address, cycle: u32) {
+        env.events().publish((Symbol::new(env, "covered"), member), (owner, cycle));
+    }
+
+    fn emit_cycle_vetoed_event(env: &Env, cycle: u32, owner: Address) {
+        env.events().publish((Symbol::new(env, "cycle_veto"), cycle), owner);
+    }
+
+    fn emit_cycle_cancelled_event(env: &Env, cycle: u32, owner: Address) {
+        env.events().publish((Symbol::new(env, "cycle_cancel"), cycle), owner);
+    }
+
+    fn emit_paused_event(env: &Env, owner: Address) {
+        env.events().publish((Symbol::new(env, "paused"), owner), env.ledger().timestamp());
+    }
+
+    fn emit_pause_vote_event(env: &Env, member: Address, votes: u32) {
+        env.events().publish((Symbol::new(env, "pause_vote"), member), votes);
+    }
+
+    fn emit_cycle_failed_event(env: &Env, cycle: u32, consecutive_failures: u32) {
+        env.events().publish((Symbol::new(env, "cycle_fail"), cycle), consecutive_failures);
+    }
+
+    fn emit_auto_paused_event(env: &Env, reason: Symbol) {
+        env.events().publish((Symbol::new(env, "auto_pause"), reason), env.ledger().timestamp());
+    }
+
+    fn emit_unpaused_event(env: &Env, owner: Address) {
+        env.events().publish((Symbol::new(env, "unpaused"), owner), env.ledger().timestamp());
+    }
 }
 
 
 // --- Utility Functions ---
 
-// Get member index from the vector
-fn get_member_index(members: &Vec<Address>, member: &Address) -> Result<u32, Error> {
-    for (i, m) in members.iter().enumerate() {
-        if m == *member {
-            return Ok(i as u32);
+// Get the member's slot, which is also their bit in the cycle bitmaps
+fn get_member_index(state: &CircleState, member: &Address) -> Result<u32, Error> {
+    for (slot, m) in state.slots.iter() {
+        if m.as_ref() == Some(member) {
+            return Ok(slot);
         }
     }
     Err(Error::NotMember)
 }
 
+// The member occupying `slot`, if any
+fn member_at(state: &CircleState, slot: u32) -> Option<Address> {
+    state.slots.get(slot).flatten()
+}
+
+// Current members in slot order, skipping empty slots
+fn active_members(env: &Env, state: &CircleState) -> Vec<Address> {
+    let mut members = Vec::new(env);
+    for (_, m) in state.slots.iter() {
+        if let Some(member) = m {
+            members.push_back(member);
+        }
+    }
+    members
+}
+
+fn member_count(state: &CircleState) -> u32 {
+    state.slots.values().iter().filter(|m| m.is_some()).count() as u32
+}
+
+// Slot a new member takes: the first empty one, else a new slot while the bitmaps have room
+fn free_slot(state: &CircleState) -> Option<u32> {
+    for (slot, m) in state.slots.iter() {
+        if m.is_none() {
+            return Some(slot);
+        }
+    }
+    if state.slots.len() < MAX_MEMBERS { Some(state.slots.len()) } else { None }
+}
+
 // Function to read and write state
 fn read_state(env: &Env) -> CircleState {
     env.storage()
@@ -114,288 +426,1945 @@ fn write_state(env: &Env, state: &CircleState) {
     env.storage().instance().set(&DataKey::CircleState, state);
 }
 
-fn read_member_state(env: &Env, member: &Address) -> MemberState {
-    env.storage()
-        .persistent()
-        .get(&DataKey::MemberRep(member.clone()))
-        .unwrap_or(MemberState { // Default state for new members
-            reputation_score: 10, // Start with a decent score
-            penalties_accrued: 0,
-            last_deposit_cycle: 0,
-        })
+fn read_member_state(env: &Env, member: &Address) -> Result<MemberState, Error> {
+    if let Some(m_state) = load_member_state(env, member)? {
+        return Ok(m_state);
+    }
+    Ok(new_member_state(read_starting_reputation(env)))
 }
 
-fn write_member_state(env: &Env, member: &Address, state: &MemberState) {
-    env.storage().persistent().set(&DataKey::MemberRep(member.clone()), state);
+// Default state for new members
+fn new_member_state(reputation_score: u32) -> MemberState {
+    MemberState {
+        reputation_score,
+        penalties_accrued: 0,
+        last_deposit_cycle: 0,
+        received_payout: false,
+        banned: false,
+        current_streak: 0,
+        best_streak: 0,
+        deposited_count: 0,
+        missed_count: 0,
+        prepaid_cycles: 0,
+        opt_out_penalty_share: false,
+        payout_address: None,
+        prepaid_balance: 0,
+        version: MEMBER_STATE_VERSION,
+    }
 }
 
-fn get_token_client(env: &Env, token_id: &Address) -> TokenClient {
-    TokenClient::new(env, token_id)
+// Circles created before the key existed fall back to the stored config
+fn read_starting_reputation(env: &Env) -> u32 {
+    if let Some(starting_reputation) = env.storage().instance().get(&DataKey::StartingReputation) {
+        return starting_reputation;
+    }
+    env.storage()
+        .instance()
+        .get::<_, CircleState>(&DataKey::CircleState)
+        .map(|state| state.config.starting_reputation)
+        .unwrap_or(DEFAULT_STARTING_REPUTATION)
 }
 
+// Reads the member's stored entry, if any, upgrading older shapes
+fn load_member_state(env: &Env, member: &Address) -> Result<Option<MemberState>, Error> {
+    let Some(raw) = env.storage().persistent().get::<_, Val>(&DataKey::MemberRep(member.clone())) else {
+        return Ok(None);
+    };
+    migrate_member(env, raw).map(Some)
+}
 
-// --- The Contract ---
-#[contract]
-pub struct SavingsCircle;
+// Copies the fields an older member layout shares with `MemberState` onto `$base`
+macro_rules! carry_over {
+    ($base:ident, $old:ident, [$($field:ident),*]) => { $( $base.$field = $old.$field; )* };
+}
 
-#[contractimpl]
-impl SavingsCircle {
-    
-    // --- Initialization & Membership ---
+// Upgrades a stored member entry to the current `MemberState`. Older layouts only ever
+// lacked fields, so each starts from a new member and keeps what it stored. The upgraded
+// entry is persisted the next time the member's state is written.
+fn migrate_member(env: &Env, raw: Val) -> Result<MemberState, Error> {
+    if let Ok(state) = MemberState::try_from_val(env, &raw) {
+        return Ok(state);
+    }
+    let mut m_state = new_member_state(0);
+    if let Ok(old) = MemberStateV3::try_from_val(env, &raw) {
+        carry_over!(m_state, old, [reputation_score, penalties_accrued, last_deposit_cycle, received_payout, banned,
+            current_streak, best_streak, deposited_count, missed_count, prepaid_cycles, opt_out_penalty_share, payout_address]);
+    } else if let Ok(old) = MemberStateV2::try_from_val(env, &raw) {
+        carry_over!(m_state, old, [reputation_score, penalties_accrued, last_deposit_cycle, received_payout, banned,
+            current_streak, best_streak, deposited_count, missed_count, prepaid_cycles, opt_out_penalty_share]);
+    } else {
+        let old = MemberStateV1::try_from_val(env, &raw).map_err(|_| Error::UnreadableState)?;
+        carry_over!(m_state, old, [reputation_score, penalties_accrued, last_deposit_cycle]);
+    }
+    Ok(m_state)
+}
 
-    /// Creates the savings circle. Only one call allowed per contract instance.
-    pub fn create_circle(
-        env: Env,
-        owner: Address,
-        token_asset: Address,
-        deposit_amount: i128,
-        members: Vec<Address>,
-        cycle_interval_secs: u64,
-        join_deadline_secs: u64,
-    ) -> Result<(), Error> {
-        owner.require_auth();
+fn write_member_state(env: &Env, member: &Address, state: &MemberState) -> Result<(), Error> {
+    let key = DataKey::MemberRep(member.clone());
 
-        if env.storage().instance().has(&DataKey::CircleState) {
-            return Err(Error::CircleExists);
-        }
-        
-        // Basic validation
-        if deposit_amount <= 0 || members.len() == 0 {
-            // More robust validation needed in production
-        }
+    // Keep the running total of claimable balances in step with this member's change
+    let old_claimable = load_member_state(env, member)?
+        .map(|old| old.penalties_accrued.max(0))
+        .unwrap_or(0);
+    let new_claimable = state.penalties_accrued.max(0);
+    if new_claimable != old_claimable {
+        let pool: i128 = env.storage().instance().get(&DataKey::PenaltyPoolTotal).unwrap_or(0);
+        let pool = pool
+            .checked_add(new_claimable)
+            .and_then(|pool| pool.checked_sub(old_claimable))
+            .ok_or(Error::Overflow)?;
+        env.storage().instance().set(&DataKey::PenaltyPoolTotal, &pool);
+    }
 
-        let config = CircleConfig {
-            owner: owner.clone(),
-            token_asset: token_asset,
-            deposit_amount,
-            cycle_interval_secs,
-            join_deadline_secs,
-        };
+    env.storage().persistent().set(&key, state);
+    Ok(())
+}
 
-        let initial_state = CircleState {
-            config,
-            members: Vec::new(&env), // Members confirm their spot with join_circle
-            member_deposits: Map::new(&env),
-            current_cycle: 1,
-            next_payout_index: 0,
-            deposits_bitmap: 0,
-            is_paused: false,
-            is_open_for_joining: true,
-        };
+// Single source of truth for whether a member owes a deposit this cycle.
+// Used by `deposit` and the penalty logic in `execute_cycle` so they never disagree.
+// Banned members are out of the rotation, so they owe nothing and aren't fined.
+fn deposit_required(env: &Env, state: &CircleState, member: &Address) -> Result<bool, Error> {
+    let m_state = read_member_state(env, member)?;
+    if m_state.banned {
+        return Ok(false);
+    }
+    if state.config.excuse_paid_members && m_state.received_payout {
+        return Ok(false);
+    }
+    Ok(true)
+}
 
-        write_state(&env, &initial_state);
-        
-        // Pre-confirm initial members for the deadline clock
-        for member in members.iter() {
-            initial_state.member_deposits.set(member, env.ledger().timestamp());
-        }
+// Validates that `member` can join the circle and returns their member state
+fn check_join(env: &Env, state: &CircleState, member: &Address) -> Result<MemberState, Error> {
+    if state.is_paused { return Err(Error::Paused); }
+    if !state.is_open_for_joining { return Err(Error::JoinDeadlinePassed); }
+    // Joining after the first cycle would reshuffle the rotation of the running round
+    if state.current_cycle > 1 { return Err(Error::CycleAlreadyStarted); }
 
-        Ok(())
+    let now = env.ledger().timestamp();
+    if now > state.joining_opened_at.saturating_add(state.config.join_deadline_secs) {
+        return Err(Error::JoinDeadlinePassed);
     }
-    
-    /// Confirms participation in the circle. Must be called before deadline.
-    pub fn join_circle(env: Env, member: Address) -> Result<(), Error> {
-        member.require_auth();
-        let mut state = read_state(&env);
 
-        if state.is_paused { return Err(Error::Paused); }
-        if !state.is_open_for_joining { return Err(Error::JoinDeadlinePassed); }
-        
-        let now = env.ledger().timestamp();
-        let creation_time = env.storage().instance().get(&DataKey::LastCycleTime).unwrap_or(0); // Use 0 for initial
-        
-        if now > creation_time + state.config.join_deadline_secs {
-             state.is_open_for_joining = false;
-             write_state(&env, &state);
-             return Err(Error::JoinDeadlinePassed);
-        }
+    if get_member_index(state, member).is_ok() {
+        return Err(Error::AlreadyJoined);
+    }
+    if !state.config.owner_is_member && *member == state.config.owner {
+        return Err(Error::OwnerCannotJoin);
+    }
+    if free_slot(state).is_none() {
+        return Err(Error::CircleFull);
+    }
+    if !state.config.allowlist.contains(member) {
+        return Err(Error::NotInvited);
+    }
+    let member_state = read_member_state(env, member)?;
+    if member_state.banned {
+        return Err(Error::MemberBanned);
+    }
+    Ok(member_state)
+}
 
-        if state.members.contains(&member) {
-            return Err(Error::AlreadyJoined);
-        }
-        
-        state.members.push_back(member.clone());
-        write_state(&env, &state);
-        
-        CircleState::emit_member_joined_event(&env, member);
+// Validates that `member` can deposit for the current cycle and returns their bitmap index
+fn check_deposit(env: &Env, state: &CircleState, member: &Address) -> Result<u32, Error> {
+    if state.is_paused { return Err(Error::Paused); }
+    if state.deposits_paused { return Err(Error::DepositsPaused); }
+    if state.is_finished { return Err(Error::CircleFinished); }
+    if env.storage().instance().has(&DataKey::PendingCycle) { return Err(Error::CyclePending); }
 
-        Ok(())
+    let member_index = get_member_index(state, member)?;
+    if read_member_state(env, member)?.banned {
+        return Err(Error::MemberBanned);
+    }
+    if !deposit_required(env, state, member)? {
+        return Err(Error::DepositNotRequired);
     }
 
+    // Check if already deposited for this cycle (using bitmap)
+    if (state.deposits_bitmap & member_bit(member_index)) != 0 {
+        return Err(Error::DepositAlreadyMade);
+    }
 
-    // --- Core Operations ---
-    
-    /// Participant deposits the fixed amount for the current cycle.
-    pub fn deposit(env: Env, depositor: Address) -> Result<(), Error> {
-        depositor.require_auth();
-        let mut state = read_state(&env);
-        
-        if state.is_paused { return Err(Error::Paused); }
-
-        let token_client = get_token_client(&env, &state.config.token_asset);
-        let amount = state.config.deposit_amount;
-        
-        // 1. Check membership
-        let member_index = get_member_index(&state.members, &depositor)?;
-        
-        // 2. Check if already deposited for this cycle (using bitmap)
-        if (state.deposits_bitmap & (1u32 << member_index)) != 0 {
-            return Err(Error::DepositAlreadyMade);
+    let now = env.ledger().timestamp();
+    if state.config.deposit_window_secs > 0 {
+        if now > cycle_start(env, state).saturating_add(state.config.deposit_window_secs) {
+            return Err(Error::DepositWindowClosed);
         }
+    }
+    if now < cycle_start(env, state).saturating_add(state.config.priority_window_secs)
+        && read_member_state(env, member)?.reputation_score < state.config.priority_min_reputation
+    {
+        return Err(Error::NotYetOpen);
+    }
+    Ok(member_index)
+}
+
+// Moves `amount` of `token` from `from` into the contract and returns what actually arrived,
+// which is less than `amount` for tokens that charge a fee on transfer
+fn transfer_in(env: &Env, token: &Address, from: &Address, amount: i128) -> Result<i128, Error> {
+    let token_client = get_token_client(env, token);
+    let contract = env.current_contract_address();
+    let balance_before = token_client.balance(&contract);
+    token_client.transfer(from, &contract, &amount);
+    token_client.balance(&contract).checked_sub(balance_before).ok_or(Error::Overflow)
+}
 
-        // 3. Transfer token from depositor to contract
-        token_client.transfer(&depositor, &env.current_contract_address(), &amount);
+// Like `transfer_in`, but pulls from the allowance `from` granted the contract
+fn transfer_in_from_allowance(env: &Env, token: &Address, from: &Address, amount: i128) -> Result<i128, Error> {
+    let token_client = get_token_client(env, token);
+    let contract = env.current_contract_address();
+    let balance_before = token_client.balance(&contract);
+    token_client.transfer_from(&contract, from, &contract, &amount);
+    token_client.balance(&contract).checked_sub(balance_before).ok_or(Error::Overflow)
+}
 
-        // 4. Update bitmap
-        state.deposits_bitmap |= 1u32 << member_index;
-        
-        // 5. Update reputation (successful deposit)
-        let mut m_state = read_member_state(&env, &depositor);
-        m_state.reputation_score = m_state.reputation_score.saturating_add(1);
-        m_state.last_deposit_cycle = state.current_cycle;
-        write_member_state(&env, &depositor, &m_state);
+// Marks the member as deposited for the current cycle and credits their reputation.
+// Moving the tokens into the contract is the caller's responsibility.
+fn record_deposit(env: &Env, state: &mut CircleState, member: &Address, member_index: u32, token: &Address, amount: i128) -> Result<(), Error> {
+    let mut m_state = read_member_state(env, member)?;
+    let record = DepositRecord {
+        token: token.clone(),
+        amount,
+        payer: None,
+        prior_deposit_cycle: m_state.last_deposit_cycle,
+        prior_best_streak: m_state.best_streak,
+    };
+    store_deposit(env, state, member, member_index, record)?;
 
-        write_state(&env, &state);
-        CircleState::emit_deposit_event(&env, depositor, state.current_cycle);
+    m_state.reputation_score = m_state.reputation_score.saturating_add(state.config.rep_gain);
+    m_state.last_deposit_cycle = state.current_cycle;
+    m_state.deposited_count = m_state.deposited_count.saturating_add(1);
 
-        Ok(())
+    m_state.current_streak = m_state.current_streak.saturating_add(1);
+    m_state.best_streak = m_state.best_streak.max(m_state.current_streak);
+    if m_state.current_streak % STREAK_BONUS_INTERVAL == 0 {
+        m_state.reputation_score = m_state.reputation_score.saturating_add(1); // Streak bonus
     }
-    
-    /// Executes the next cycle, handles payouts, and applies penalties.
-    /// This function is intended to be called by an external relayer/frontend.
-    pub fn execute_cycle(env: Env) -> Result<(), Error> {
-        // No auth check on the caller, as it's an external trigger
-        let mut state = read_state(&env);
+    write_member_state(env, member, &m_state)?;
 
-        if state.is_paused { return Err(Error::Paused); }
-        
-        let now = env.ledger().timestamp();
-        let last_cycle_time: u64 = env.storage().instance().get(&DataKey::LastCycleTime).unwrap_or(0); // 0 for the very first execution
+    write_state(env, state);
+    CircleState::emit_deposit_event(env, member.clone(), state.current_cycle);
+    Ok(())
+}
 
-        // 1. Check Cycle Scheduling
-        if last_cycle_time != 0 && now < last_cycle_time + state.config.cycle_interval_secs {
-            return Err(Error::CycleNotReady);
-        }
+// Reverses the reputation and streak credit `record_deposit` gave for a refunded deposit
+fn unrecord_deposit(env: &Env, state: &CircleState, member: &Address, record: &DepositRecord) -> Result<(), Error> {
+    let mut m_state = read_member_state(env, member)?;
+    if m_state.current_streak > 0 && m_state.current_streak % STREAK_BONUS_INTERVAL == 0 {
+        m_state.reputation_score = m_state.reputation_score.saturating_sub(1); // Streak bonus
+    }
+    m_state.reputation_score = m_state.reputation_score.saturating_sub(state.config.rep_gain);
+    m_state.deposited_count = m_state.deposited_count.saturating_sub(1);
+    m_state.current_streak = m_state.current_streak.saturating_sub(1);
+    m_state.last_deposit_cycle = record.prior_deposit_cycle;
+    m_state.best_streak = record.prior_best_streak;
+    write_member_state(env, member, &m_state)
+}
 
-        let num_members = state.members.len();
-        if num_members == 0 {
-            // Cannot execute cycle without members, but this shouldn't happen if join_circle is used correctly
-            return Err(Error::NotFound);
+// Records the deposit held for the member's slot this cycle, with its receipt and timestamp.
+// `token_asset` deposits are forwarded to the yield adapter if one is configured.
+fn store_deposit(env: &Env, state: &mut CircleState, member: &Address, member_index: u32, record: DepositRecord) -> Result<(), Error> {
+    let token = record.token.clone();
+    let amount = record.amount;
+    state.deposits_bitmap |= member_bit(member_index);
+    // Alternative tokens count at their nominal `token_asset` value
+    let value = if token == state.config.token_asset { amount } else { state.config.deposit_amount };
+    state.current_cycle_deposited = state.current_cycle_deposited
+        .checked_add(value)
+        .ok_or(Error::Overflow)?;
+    state.cycle_deposits.set(member.clone(), record);
+    state.member_deposits.set(member.clone(), env.ledger().timestamp());
+    env.storage().persistent().set(&DataKey::Receipt(member.clone(), state.season, state.current_cycle), &true);
+
+    if let Some(yield_contract) = state.config.yield_contract.clone() {
+        if token == state.config.token_asset {
+            let contract = env.current_contract_address();
+            get_token_client(env, &token).transfer(&contract, &yield_contract, &amount);
+            YieldClient::new(env, &yield_contract).deposit(&contract, &amount);
+            state.yield_principal = state.yield_principal.checked_add(amount).ok_or(Error::Overflow)?;
         }
+    }
+    Ok(())
+}
 
-        let token_client = get_token_client(&env, &state.config.token_asset);
-        let deposit_amount = state.config.deposit_amount;
-        let total_pot = deposit_amount.checked_mul(num_members as i128).unwrap_infallible();
-        let payout_recipient = state.members.get(state.next_payout_index).unwrap_infallible();
+// When the current cycle opened: the last execution, or the join window for the first cycle
+fn cycle_start(env: &Env, state: &CircleState) -> u64 {
+    let last_cycle_time: u64 = env.storage().instance().get(&DataKey::LastCycleTime).unwrap_or(0);
+    if last_cycle_time == 0 { state.joining_opened_at } else { last_cycle_time }
+}
 
-        // --- Penalty & Reputation Logic ---
-        
-        let penalty_missed_mult: i128 = 20; // 20% penalty
-        let base_penalty_amount = deposit_amount.checked_div(100).unwrap_infallible();
-        
-        let mut pooled_penalties: i128 = 0;
+// Seconds until `execute_cycle` passes its scheduling check (0 = can run now)
+fn secs_until_executable(env: &Env, state: &CircleState) -> u64 {
+    let last_cycle_time: u64 = env.storage().instance().get(&DataKey::LastCycleTime).unwrap_or(0); // 0 for the very first execution
+    if last_cycle_time == 0 {
+        return state.joining_opened_at
+            .saturating_add(state.config.first_cycle_delay_secs)
+            .saturating_sub(env.ledger().timestamp());
+    }
+    last_cycle_time
+        .saturating_add(state.config.cycle_interval_secs)
+        .saturating_sub(env.ledger().timestamp())
+}
 
-        for i in 0..num_members {
-            let member_addr = state.members.get(i as u32).unwrap_infallible();
-            let is_deposited = (state.deposits_bitmap & (1u32 << i)) != 0;
-            
-            if !is_deposited {
-                // Member has NOT deposited. This is a MISSED DEPOSIT.
-                let mut m_state = read_member_state(&env, &member_addr);
-                
-                // Penalty value: 20% of deposit
-                let penalty_value = base_penalty_amount.checked_mul(penalty_missed_mult).unwrap_infallible();
-                
-                // NOTE: In the contract, we can't force the transfer from a member here unless they authorized it.
-                // For simplicity, the penalty is accrued to the member's account. They are *fined* this amount.
-                m_state.penalties_accrued = m_state.penalties_accrued.checked_sub(penalty_value).unwrap_infallible(); // Fined: subtract penalty from their claimable balance
-                pooled_penalties = pooled_penalties.checked_add(penalty_value).unwrap_infallible(); // Add penalty value to the pot to be distributed
-                
-                m_state.reputation_score = m_state.reputation_score.saturating_sub(1); // Decrease score
-                
-
-                write_member_state(&env, &member_addr, &m_state);
-                CircleState::emit_penalty_event(&env, member_addr, state.current_cycle, penalty_value, false);
-            }
-        }
-        
-        // --- Payout Logic ---
-        
-        // 1. Payout: The recipient receives the total pot of collected deposits
-        token_client.transfer(&env.current_contract_address(), &payout_recipient, &total_pot);
-
-        // 2. Penalty Distribution: All collected penalties are distributed equally among ALL members 
-        // by increasing their claimable balance.
-        if pooled_penalties > 0 {
-            let penalty_share = pooled_penalties.checked_div(num_members as i128).unwrap_infallible();
-
-            for member in state.members.iter() {
-                let mut m_state = read_member_state(&env, &member);
-                m_state.penalties_accrued = m_state.penalties_accrued.checked_add(penalty_share).unwrap_infallible();
-                write_member_state(&env, &member, &m_state);
-            }
-        }
+// Deterministic Fisher-Yates shuffle of the member indices `0..len`. The seed comes from
+// the ledger sequence and timestamp, so the order can be re-derived and audited later.
+fn shuffled_sequence(env: &Env, len: u32) -> Vec<u32> {
+    let mut sequence = Vec::new(env);
+    for i in 0..len {
+        sequence.push_back(i);
+    }
 
-        CircleState::emit_payout_event(&env, payout_recipient, state.current_cycle, total_pot);
+    let mut seed: u64 = ((env.ledger().sequence() as u64) << 32) ^ env.ledger().timestamp();
+    seed |= 1; // xorshift never leaves zero
+    let mut i = len;
+    while i > 1 {
+        i -= 1;
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        let j = (seed % (i as u64 + 1)) as u32;
 
-        // --- Advance Cycle State ---
-        
-        state.current_cycle = state.current_cycle.checked_add(1).unwrap_infallible();
-        
-        // Rotate the payout index
-        state.next_payout_index = (state.next_payout_index.checked_add(1).unwrap_infallible()) % num_members;
+        let a = sequence.get(i).unwrap();
+        let b = sequence.get(j).unwrap();
+        sequence.set(i, b);
+        sequence.set(j, a);
+    }
+    sequence
+}
 
-        // Reset the deposit bitmap for the new cycle
-        state.deposits_bitmap = 0;
-        
-        // Update last execution time
-        env.storage().instance().set(&DataKey::LastCycleTime, &now);
+// Maps a rotation position to the slot that gets paid at that position
+fn payout_member_index(state: &CircleState, position: u32) -> u32 {
+    match state.config.payout_order {
+        PayoutOrder::Sequential => position,
+        PayoutOrder::Random => state.payout_sequence.get(position).unwrap_or(position),
+    }
+}
 
-        write_state(&env, &state);
-        CircleState::emit_cycle_executed_event(&env, state.current_cycle - 1, payout_recipient);
+// Remaining payouts of the current round as (cycle, recipient) pairs, in rotation order
+fn remaining_schedule(env: &Env, state: &CircleState) -> Result<Vec<(u32, Address)>, Error> {
+    let num_slots = state.slots.len();
+    let mask = round_mask(env, state)?;
 
-        Ok(())
+    let mut schedule = Vec::new(env);
+    let mut cycle = state.current_cycle;
+    for step in 0..num_slots {
+        let position = (state.next_payout_index + step) % num_slots;
+        let member_index = payout_member_index(state, position);
+        if state.paid_this_round & member_bit(member_index) != 0 || mask & member_bit(member_index) == 0 {
+            continue;
+        }
+        if let Some(member) = member_at(state, member_index) {
+            schedule.push_back((cycle, member));
+            cycle = cycle.checked_add(1).ok_or(Error::Overflow)?;
+        }
     }
+    Ok(schedule)
+}
 
+// Bit for the member at `index`. Indices past the bitmap width map to no bit rather than
+// wrapping around onto a lower member.
+fn member_bit(index: u32) -> u64 {
+    1u64.checked_shl(index).unwrap_or(0)
+}
 
-    // --- Admin & Utility ---
-    
-    /// Allows a member to claim their accumulated refunds/penalties (positive balance).
-    pub fn claim_refund(env: Env, member: Address) -> Result<(), Error> {
-        member.require_auth();
-        let state = read_state(&env);
-        
-        let mut m_state = read_member_state(&env, &member);
-        
-        let amount = m_state.penalties_accrued;
-        if amount <= 0 {
-            return Ok(()); // Nothing to claim or member owes a fine
+// Bitmap with one bit set per member taking part in the rotation. Banned members sit out.
+fn round_mask(env: &Env, state: &CircleState) -> Result<u64, Error> {
+    let mut mask = 0;
+    for (slot, m) in state.slots.iter() {
+        let Some(member) = m else { continue };
+        if !read_member_state(env, &member)?.banned {
+            mask |= member_bit(slot);
         }
-        
-        let token_client = get_token_client(&env, &state.config.token_asset);
-        
-        // Transfer collected penalties from contract to member
-        token_client.transfer(&env.current_contract_address(), &member, &amount);
-        
-        // Reset accrued penalties
-        m_state.penalties_accrued = 0;
-        write_member_state(&env, &member, &m_state);
-        
-        Ok(())
     }
+    Ok(mask)
+}
 
-    /// Emergency pause for the circle.
-    pub fn pause(env: Env, owner: Address) -> Result<(), Error> {
-        owner.require_auth();
-        let mut state = read_state(&env);
+// First rotation position, starting at the cursor, whose member hasn't been paid this round.
+// Empty slots and banned members are passed over.
+// With `skip_defaulting_recipient`, members who missed this cycle's deposit are passed over
+// (keeping their turn) unless nobody eligible is left.
+fn next_unpaid_position(env: &Env, state: &CircleState) -> Result<u32, Error> {
+    let num_slots = state.slots.len();
+    let mask = round_mask(env, state)?;
+    let mut first_unpaid = None;
+    for step in 0..num_slots {
+        let position = (state.next_payout_index + step) % num_slots;
+        let member_index = payout_member_index(state, position);
+        if state.paid_this_round & member_bit(member_index) != 0 || mask & member_bit(member_index) == 0 {
+            continue;
+        }
+        let Some(member) = member_at(state, member_index) else {
+            continue;
+        };
+        if !state.config.skip_defaulting_recipient {
+            return Ok(position);
+        }
+        first_unpaid = first_unpaid.or(Some(position));
 
-        if state.config.owner != owner {
-            return Err(Error::NotOwner);
+        let deposited = state.deposits_bitmap & member_bit(member_index) != 0;
+        if deposited || !deposit_required(env, state, &member)? {
+            return Ok(position);
         }
-        
-        state.is_paused = true;
-        write_state(&env, &state);
-        Ok(())
+    }
+    // Keep the fallback in range even if the cursor outgrew the slot list
+    Ok(first_unpaid.unwrap_or(state.next_payout_index % num_slots.max(1)))
+}
+
+// Ends the rotation round: everyone becomes eligible for a payout again. Finishing the
+// circle returns any prepaid cycles that will now never be drawn.
+fn complete_round(env: &Env, state: &mut CircleState) -> Result<(), Error> {
+    state.paid_this_round = 0;
+    state.next_payout_index = 0;
+    state.completed_rounds = state.completed_rounds.checked_add(1).ok_or(Error::Overflow)?;
+    if state.config.max_rounds != 0 && state.completed_rounds >= state.config.max_rounds {
+        state.is_finished = true;
+    }
+
+    for member in active_members(env, state).iter() {
+        let mut m_state = read_member_state(env, &member)?;
+        m_state.received_payout = false;
+        if state.is_finished {
+            refund_prepaid_cycles(env, state, &member, &mut m_state)?;
+        }
+        write_member_state(env, &member, &m_state)?;
+    }
+    Ok(())
+}
+
+// Sends the member's unused prepaid cycles back to them
+fn refund_prepaid_cycles(env: &Env, state: &CircleState, member: &Address, m_state: &mut MemberState) -> Result<(), Error> {
+    if m_state.prepaid_cycles == 0 {
+        return Ok(());
+    }
+    let amount = state.config.deposit_amount
+        .checked_mul(m_state.prepaid_cycles as i128)
+        .ok_or(Error::Overflow)?;
+    m_state.prepaid_cycles = 0;
+    add_prepaid_total(env, -amount)?;
+    get_token_client(env, &state.config.token_asset).transfer(&env.current_contract_address(), member, &amount);
+    Ok(())
+}
+
+// Amount of `token` that covers one deposit, converted at the configured rate
+fn deposit_in_token(config: &CircleConfig, token: &Address) -> Result<i128, Error> {
+    if !config.accepted_tokens.contains(token) {
+        return Err(Error::TokenNotAccepted);
+    }
+    let rate = config.token_rates.get(token.clone()).unwrap_or(RATE_SCALE);
+    Ok(config.deposit_amount.checked_mul(rate).ok_or(Error::Overflow)? / RATE_SCALE)
+}
+
+// Pays `pot` of `token` to the recipient after taking the protocol fee; returns the net amount.
+// Only the balance above `reserved` may be spent.
+fn pay_out(env: &Env, config: &CircleConfig, token: &Address, recipient: &Address, pot: i128, reserved: i128) -> Result<i128, Error> {
+    let token_client = get_token_client(env, token);
+    let available = token_client.balance(&env.current_contract_address()).checked_sub(reserved).ok_or(Error::Overflow)?;
+    if available < pot {
+        return Err(Error::InsufficientFunds);
+    }
+
+    // 1. Fee: The protocol cut is taken off the top of the pot
+    let fee = pot
+        .checked_mul(config.fee_bps as i128)
+        .ok_or(Error::Overflow)?
+        .checked_div(MAX_BPS as i128)
+        .ok_or(Error::Overflow)?;
+    if fee > 0 {
+        token_client.transfer(&env.current_contract_address(), &config.fee_recipient, &fee);
+    }
+
+    // 2. Payout: The recipient receives the rest of the pot
+    let net_payout = pot.checked_sub(fee).ok_or(Error::Overflow)?;
+    if net_payout > 0 {
+        token_client.transfer(&env.current_contract_address(), recipient, &net_payout);
+    }
+    Ok(net_payout)
+}
+
+// Checks pause/finish flags, membership, and that the current cycle hasn't already run
+fn check_cycle_runnable(state: &CircleState) -> Result<(), Error> {
+    if state.is_paused { return Err(Error::Paused); }
+    if state.deposits_paused { return Err(Error::DepositsPaused); }
+    if state.is_finished { return Err(Error::CircleFinished); }
+
+    if state.last_executed_cycle >= state.current_cycle {
+        return Err(Error::CycleNotReady);
+    }
+    if member_count(state) == 0 {
+        // Cannot execute cycle without members, but this shouldn't happen if join_circle is used correctly
+        return Err(Error::NotFound);
+    }
+    Ok(())
+}
+
+// Checks pause/finish flags, the schedule, and idempotency before a cycle can run
+fn check_cycle_due(env: &Env, state: &CircleState) -> Result<(), Error> {
+    check_cycle_runnable(state)?;
+
+    let now = env.ledger().timestamp();
+
+    // 1. Check Cycle Scheduling
+    if secs_until_executable(env, state) > 0 {
+        return Err(Error::CycleNotReady);
+    }
+
+    // 2. Idempotency: two cycles can't run within a single ledger timestamp
+    // (possible with a zero interval)
+    let last_cycle_time: u64 = env.storage().instance().get(&DataKey::LastCycleTime).unwrap_or(0);
+    if last_cycle_time != 0 && now <= last_cycle_time {
+        return Err(Error::CycleNotReady);
+    }
+    Ok(())
+}
+
+// Pulls parked deposits (plus any yield) back from the yield adapter; returns the surplus
+fn withdraw_from_yield(env: &Env, state: &mut CircleState) -> Result<i128, Error> {
+    let mut yield_surplus: i128 = 0;
+    if let Some(yield_contract) = state.config.yield_contract.clone() {
+        if state.yield_principal > 0 {
+            let withdrawn = YieldClient::new(env, &yield_contract).withdraw(&env.current_contract_address());
+            yield_surplus = withdrawn.checked_sub(state.yield_principal).ok_or(Error::Overflow)?.max(0);
+            state.yield_principal = 0;
+        }
+    }
+    Ok(yield_surplus)
+}
+
+// Returns every deposit recorded this cycle to its depositor and clears the cycle's deposits
+fn refund_cycle_deposits(env: &Env, state: &mut CircleState) -> Result<(), Error> {
+    withdraw_from_yield(env, state)?;
+
+    let contract = env.current_contract_address();
+    for (member, record) in state.cycle_deposits.iter() {
+        let refund_to = record.payer.clone().unwrap_or(member.clone());
+        get_token_client(env, &record.token).transfer(&contract, &refund_to, &record.amount);
+        // Covered deposits earned the member nothing to take back
+        if record.payer.is_none() {
+            unrecord_deposit(env, state, &member, &record)?;
+        }
+        env.storage().persistent().remove(&DataKey::Receipt(member, state.season, state.current_cycle));
+    }
+    state.deposits_bitmap = 0;
+    state.current_cycle_deposited = 0;
+    state.cycle_deposits = Map::new(env);
+    state.member_deposits = Map::new(env);
+    Ok(())
+}
+
+// Members who prepaid and haven't deposited this cycle, with their slots
+fn prepaid_members(env: &Env, state: &CircleState) -> Result<Vec<(u32, Address)>, Error> {
+    let mut prepaid = Vec::new(env);
+    for (i, slot) in state.slots.iter() {
+        let Some(member) = slot else { continue };
+        if state.deposits_bitmap & member_bit(i) != 0 || !deposit_required(env, state, &member)? {
+            continue;
+        }
+        let m_state = read_member_state(env, &member)?;
+        if m_state.prepaid_cycles > 0 || m_state.prepaid_balance >= state.config.deposit_amount {
+            prepaid.push_back((i, member));
+        }
+    }
+    Ok(prepaid)
+}
+
+// The cycle's pot in `token_asset`, plus the pots of deposits made in other whitelisted tokens
+fn cycle_pots(env: &Env, state: &CircleState) -> Result<(i128, Map<Address, i128>), Error> {
+    // Only members who owe a deposit this cycle contribute to the pot
+    let mut expected_depositors: u32 = 0;
+    for member in active_members(env, state).iter() {
+        if deposit_required(env, state, &member)? {
+            expected_depositors += 1;
+        }
+    }
+
+    // Deposits made in other whitelisted tokens are paid out in those tokens
+    let mut alt_pots: Map<Address, i128> = Map::new(env);
+    let mut alt_depositors: u32 = 0;
+    let mut primary_received: i128 = 0;
+    let mut primary_records: u32 = 0;
+    for record in state.cycle_deposits.values().iter() {
+        if record.token != state.config.token_asset {
+            let pot = alt_pots.get(record.token.clone()).unwrap_or(0);
+            alt_pots.set(record.token.clone(), pot.checked_add(record.amount).ok_or(Error::Overflow)?);
+            alt_depositors += 1;
+        } else {
+            primary_received = primary_received.checked_add(record.amount).ok_or(Error::Overflow)?;
+            primary_records += 1;
+        }
+    }
+    // Deposits count at what the contract actually received (less any transfer fee);
+    // the shares of members who missed count at the nominal deposit amount
+    let primary_depositors = expected_depositors.saturating_sub(alt_depositors);
+    let primary_missing = primary_depositors.saturating_sub(primary_records);
+    let total_pot = state.config.deposit_amount
+        .checked_mul(primary_missing as i128)
+        .ok_or(Error::Overflow)?
+        .checked_add(primary_received)
+        .ok_or(Error::Overflow)?;
+    Ok((total_pot, alt_pots))
+}
+
+// Picks this cycle's recipient, drawing the random order first if it hasn't been drawn.
+// Returns (rotation position, slot, recipient).
+fn select_recipient(env: &Env, state: &mut CircleState) -> Result<(u32, u32, Address), Error> {
+    let num_slots = state.slots.len();
+    if state.config.payout_order == PayoutOrder::Random && state.payout_sequence.len() != num_slots {
+        state.payout_sequence = shuffled_sequence(env, num_slots);
+    }
+    let payout_position = next_unpaid_position(env, state)?;
+    let payout_index = payout_member_index(state, payout_position);
+    let payout_recipient = member_at(state, payout_index).ok_or(Error::NotFound)?;
+    // Only reachable when every member left in the round is banned
+    if read_member_state(env, &payout_recipient)?.banned {
+        return Err(Error::MemberBanned);
+    }
+    Ok((payout_position, payout_index, payout_recipient))
+}
+
+// Pulls `fine` of `token_asset` from the member's allowance to the contract when the allowance
+// and balance cover it in full; returns what arrived (0 if nothing was collected)
+fn collect_fine(env: &Env, state: &CircleState, member: &Address, fine: i128) -> Result<i128, Error> {
+    if fine <= 0 {
+        return Ok(0);
+    }
+    let token = &state.config.token_asset;
+    let token_client = get_token_client(env, token);
+    let contract = env.current_contract_address();
+    if token_client.allowance(member, &contract) < fine || token_client.balance(member) < fine {
+        return Ok(0);
+    }
+    transfer_in_from_allowance(env, token, member, fine)
+}
+
+// Fines the member `fine`, limited by the per-member cap. A member with a standing allowance
+// pays in tokens right away; otherwise, or for whatever didn't arrive, the fine is subtracted
+// from their claimable balance. Returns the amount levied, the part paid in tokens, and the
+// part that is neither paid nor covered by existing credit, i.e. new debt.
+fn levy_fine(env: &Env, state: &CircleState, member: &Address, m_state: &mut MemberState, fine: i128) -> Result<(i128, i128, i128), Error> {
+    let mut fine = fine;
+
+    // Cumulative fines stop at the configured cap
+    if state.config.max_fine_per_member > 0 {
+        let headroom = m_state.penalties_accrued
+            .checked_add(state.config.max_fine_per_member)
+            .ok_or(Error::Overflow)?
+            .max(0);
+        fine = fine.min(headroom);
+    }
+
+    let collected = collect_fine(env, state, member, fine)?;
+    let owed = fine.checked_sub(collected).ok_or(Error::Overflow)?;
+    let covered_by_credit = owed.min(m_state.penalties_accrued.max(0));
+    m_state.penalties_accrued = m_state.penalties_accrued.checked_sub(owed).ok_or(Error::Overflow)?;
+    Ok((fine, collected, owed.checked_sub(covered_by_credit).ok_or(Error::Overflow)?))
+}
+
+// Records that `creditor` is owed `amount` of `debtor`'s fine, paid as the debtor repays
+fn owe_fine_to(env: &Env, debtor: &Address, creditor: &Address, amount: i128) {
+    let key = DataKey::FineCreditors(debtor.clone());
+    let mut creditors: Vec<(Address, i128)> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    creditors.push_back((creditor.clone(), amount));
+    env.storage().persistent().set(&key, &creditors);
+}
+
+// Passes a fine repayment on to the debtor's creditors, oldest first, as claimable credit
+fn pay_fine_creditors(env: &Env, debtor: &Address, repayment: i128) -> Result<(), Error> {
+    let key = DataKey::FineCreditors(debtor.clone());
+    let Some(mut creditors) = env.storage().persistent().get::<_, Vec<(Address, i128)>>(&key) else {
+        return Ok(());
+    };
+
+    let mut remaining = repayment;
+    while remaining > 0 {
+        let Some((creditor, owed)) = creditors.first() else { break };
+        let paid = remaining.min(owed);
+        let mut c_state = read_member_state(env, &creditor)?;
+        c_state.penalties_accrued = c_state.penalties_accrued.checked_add(paid).ok_or(Error::Overflow)?;
+        write_member_state(env, &creditor, &c_state)?;
+
+        remaining = remaining.checked_sub(paid).ok_or(Error::Overflow)?;
+        if paid == owed {
+            creditors.pop_front();
+        } else {
+            creditors.set(0, (creditor, owed.checked_sub(paid).ok_or(Error::Overflow)?));
+        }
+    }
+
+    if creditors.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &creditors);
+    }
+    Ok(())
+}
+
+// Fine for a deposit made after `late_after_secs` into the cycle: 10% of the deposit, scaled
+// by how far into the grace period it arrived (full once the grace period has passed)
+fn late_fine(env: &Env, state: &CircleState, member: &Address) -> Result<i128, Error> {
+    let grace = state.config.late_grace_period_secs;
+    if grace == 0 {
+        return Ok(0);
+    }
+    let Some(deposited_at) = state.member_deposits.get(member.clone()) else {
+        return Ok(0);
+    };
+    let due = cycle_start(env, state).saturating_add(state.config.late_after_secs);
+    let late_secs = deposited_at.saturating_sub(due).min(grace);
+
+    let full_fine = state.config.deposit_amount.checked_div(10).ok_or(Error::Overflow)?;
+    Ok(full_fine.checked_mul(late_secs as i128).ok_or(Error::Overflow)? / grace as i128)
+}
+
+// Handles a cycle that missed its quorum. With auto-pause enabled, one failure per cycle
+// interval is counted and the call succeeds so the count is kept (an error would revert it);
+// reaching `max_failures_before_pause` pauses the circle. Otherwise fails with `QuorumNotMet`.
+fn record_failed_cycle(env: &Env, mut state: CircleState) -> Result<(), Error> {
+    let now = env.ledger().timestamp();
+    let counted = state.last_failure_time == 0
+        || now >= state.last_failure_time.saturating_add(state.config.cycle_interval_secs);
+    if state.config.max_failures_before_pause == 0 || !counted {
+        return Err(Error::QuorumNotMet);
+    }
+
+    state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+    state.last_failure_time = now;
+    CircleState::emit_cycle_failed_event(env, state.current_cycle, state.consecutive_failures);
+    if state.consecutive_failures >= state.config.max_failures_before_pause {
+        state.is_paused = true;
+        CircleState::emit_auto_paused_event(env, Symbol::new(env, "quorum"));
+    }
+    write_state(env, &state);
+    Ok(())
+}
+
+// Applies penalties, pays out the pot, and advances to the next cycle.
+// Callers must have run `check_cycle_due` first.
+fn run_cycle(env: &Env, mut state: CircleState) -> Result<(), Error> {
+    let now = env.ledger().timestamp();
+    let members = active_members(env, &state);
+    let num_slots = state.slots.len();
+
+    // Members who prepaid and haven't deposited are credited from their prepaid cycles,
+    // or else auto-deposited from their funded balance
+    for (i, member) in prepaid_members(env, &state)?.iter() {
+        let mut m_state = read_member_state(env, &member)?;
+        if m_state.prepaid_cycles > 0 {
+            m_state.prepaid_cycles = m_state.prepaid_cycles.checked_sub(1).ok_or(Error::Overflow)?;
+        } else {
+            m_state.prepaid_balance = m_state.prepaid_balance
+                .checked_sub(state.config.deposit_amount)
+                .ok_or(Error::Overflow)?;
+        }
+        write_member_state(env, &member, &m_state)?;
+        add_prepaid_total(env, -state.config.deposit_amount)?;
+        let token = state.config.token_asset.clone();
+        let amount = state.config.deposit_amount;
+        record_deposit(env, &mut state, &member, i, &token, amount)?;
+        state.member_deposits.remove(member); // Paid in advance, so never late
+    }
+
+    // Without a quorum the cycle stays open: deposits carry forward and nobody is paid.
+    // Missers are penalized once the cycle eventually runs (an error reverts any writes here).
+    if state.deposits_bitmap.count_ones() < state.config.min_deposits_for_payout {
+        return record_failed_cycle(env, state);
+    }
+    state.consecutive_failures = 0;
+
+    let deposit_amount = state.config.deposit_amount;
+    let (total_pot, alt_pots) = cycle_pots(env, &state)?;
+    let (payout_position, payout_index, payout_recipient) = select_recipient(env, &mut state)?;
+
+    // --- Penalty & Reputation Logic ---
+    
+    let penalty_missed_mult: i128 = 20; // 20% penalty
+    let base_penalty_amount = deposit_amount.checked_div(100).ok_or(Error::Overflow)?;
+    
+    let mut pooled_penalties: i128 = 0;
+    let mut collected_penalties: i128 = 0; // Part of the pool paid in tokens
+    let mut unpaid_penalties: i128 = 0; // Part of the pool left as new debt
+    let mut unpaid_fines: Vec<(Address, i128)> = Vec::new(env); // New debt from this cycle's fines
+    let mut num_missed: u32 = 0;
+
+    // Slots are capped at MAX_MEMBERS, so every slot has its own bit; empty slots owe nothing
+    for (i, slot) in state.slots.iter() {
+        let Some(member_addr) = slot else { continue };
+        let is_deposited = (state.deposits_bitmap & member_bit(i)) != 0;
+        
+        if !is_deposited && deposit_required(env, &state, &member_addr)? {
+            // Member has NOT deposited. This is a MISSED DEPOSIT.
+            let mut m_state = read_member_state(env, &member_addr)?;
+            
+            // Penalty value: 20% of deposit
+            let penalty_value = base_penalty_amount.checked_mul(penalty_missed_mult).ok_or(Error::Overflow)?;
+            let (penalty_value, collected, unpaid) = levy_fine(env, &state, &member_addr, &mut m_state, penalty_value)?;
+            collected_penalties = collected_penalties.checked_add(collected).ok_or(Error::Overflow)?;
+            unpaid_penalties = unpaid_penalties.checked_add(unpaid).ok_or(Error::Overflow)?;
+            if unpaid > 0 {
+                unpaid_fines.push_back((member_addr.clone(), unpaid));
+            }
+            pooled_penalties = pooled_penalties.checked_add(penalty_value).ok_or(Error::Overflow)?; // Add penalty value to the pot to be distributed
+            
+            m_state.reputation_score = m_state.reputation_score.saturating_sub(state.config.rep_loss); // Decrease score
+            // Long-inactive members decay further on top of the per-miss loss
+            if state.current_cycle.saturating_sub(m_state.last_deposit_cycle) > state.config.inactivity_gap_cycles {
+                m_state.reputation_score = m_state.reputation_score.saturating_sub(state.config.reputation_decay_per_cycle);
+            }
+            if m_state.reputation_score < state.config.min_reputation {
+                m_state.banned = true;
+            }
+            m_state.current_streak = 0;
+            m_state.missed_count = m_state.missed_count.saturating_add(1);
+
+            write_member_state(env, &member_addr, &m_state)?;
+            CircleState::emit_penalty_event(env, member_addr, state.current_cycle, penalty_value, false, state.config.token_decimals);
+            num_missed += 1;
+        } else if is_deposited {
+            // Deposited, but possibly LATE: fined in proportion to the lateness
+            let late_value = late_fine(env, &state, &member_addr)?;
+            if late_value > 0 {
+                let mut m_state = read_member_state(env, &member_addr)?;
+                let (late_value, collected, unpaid) = levy_fine(env, &state, &member_addr, &mut m_state, late_value)?;
+                collected_penalties = collected_penalties.checked_add(collected).ok_or(Error::Overflow)?;
+                unpaid_penalties = unpaid_penalties.checked_add(unpaid).ok_or(Error::Overflow)?;
+                if unpaid > 0 {
+                    unpaid_fines.push_back((member_addr.clone(), unpaid));
+                }
+                pooled_penalties = pooled_penalties.checked_add(late_value).ok_or(Error::Overflow)?;
+                write_member_state(env, &member_addr, &m_state)?;
+                CircleState::emit_penalty_event(env, member_addr, state.current_cycle, late_value, true, state.config.token_decimals);
+            }
+        }
+    }
+    
+    // --- Payout Logic ---
+    
+    // 0. Yield: Pull parked deposits (plus any yield) back before paying out
+    let yield_surplus = withdraw_from_yield(env, &mut state)?;
+
+    // 1. Bonus: In a finite circle a slice of each pot is set aside, and the very last payout
+    // of the final round receives everything set aside on top of its pot
+    let mut payout_pot = total_pot;
+    if state.config.max_rounds != 0 {
+        let skim = total_pot
+            .checked_mul(state.config.bonus_skim_bps as i128)
+            .ok_or(Error::Overflow)?
+            / MAX_BPS as i128;
+        state.bonus_reserve = state.bonus_reserve.checked_add(skim).ok_or(Error::Overflow)?;
+        payout_pot = payout_pot.checked_sub(skim).ok_or(Error::Overflow)?;
+
+        let mask = round_mask(env, &state)?;
+        let completes_round = (state.paid_this_round | member_bit(payout_index)) & mask == mask;
+        let rounds_after = state.completed_rounds.checked_add(1).ok_or(Error::Overflow)?;
+        if completes_round && rounds_after >= state.config.max_rounds {
+            payout_pot = payout_pot.checked_add(state.bonus_reserve).ok_or(Error::Overflow)?;
+            state.bonus_reserve = 0;
+        }
+    }
+
+    // Under `ToRecipient` the fines actually paid top up this cycle's payout; the recipient
+    // is paid the rest as the fined members repay it
+    if state.config.penalty_policy == PenaltyPolicy::ToRecipient {
+        let mut paid_penalties = pooled_penalties;
+        for (debtor, unpaid) in unpaid_fines.iter() {
+            paid_penalties = paid_penalties.checked_sub(unpaid).ok_or(Error::Overflow)?;
+            owe_fine_to(env, &debtor, &payout_recipient, unpaid);
+        }
+        payout_pot = payout_pot.checked_add(paid_penalties).ok_or(Error::Overflow)?;
+    }
+
+    // Fines paid in tokens back the credits they become. Credit spent covering a fine moves to
+    // the other members, unless the pool leaves the members (`ToRecipient`, `Burn`).
+    let covered_penalties = pooled_penalties
+        .checked_sub(collected_penalties)
+        .and_then(|covered| covered.checked_sub(unpaid_penalties))
+        .ok_or(Error::Overflow)?;
+    let mut credited_penalties: i128 = 0; // Collected fines credited below, after the payout
+    match state.config.penalty_policy {
+        PenaltyPolicy::Redistribute | PenaltyPolicy::ToOwner => {
+            add_backed_credit(env, collected_penalties)?;
+            credited_penalties = collected_penalties;
+        }
+        PenaltyPolicy::ToRecipient | PenaltyPolicy::Burn => add_backed_credit(env, -covered_penalties)?,
+    }
+
+    // 2. Payout: The recipient receives the pot, less the protocol fee, in each deposited token,
+    // at their payout address if they set one
+    let payout_to = read_member_state(env, &payout_recipient)?.payout_address.unwrap_or(payout_recipient.clone());
+    let reserved = reserved_funds(env, &state, &state.config.token_asset, 0)?
+        .checked_add(credited_penalties)
+        .ok_or(Error::Overflow)?;
+    let net_payout = pay_out(env, &state.config, &state.config.token_asset, &payout_to, payout_pot, reserved)?;
+    for (token, pot) in alt_pots.iter() {
+        pay_out(env, &state.config, &token, &payout_to, pot, reserved_funds(env, &state, &token, 0)?)?;
+    }
+
+    // Surplus yield is shared pro-rata among this cycle's `token_asset` depositors
+    if yield_surplus > 0 {
+        let mut yield_earners = Vec::new(env);
+        for (member, record) in state.cycle_deposits.iter() {
+            if record.token == state.config.token_asset {
+                yield_earners.push_back(member);
+            }
+        }
+        if yield_earners.len() > 0 {
+            let yield_share = yield_surplus.checked_div(yield_earners.len() as i128).ok_or(Error::Overflow)?;
+            add_backed_credit(env, yield_share.checked_mul(yield_earners.len() as i128).ok_or(Error::Overflow)?)?;
+            for member in yield_earners.iter() {
+                let mut m_state = read_member_state(env, &member)?;
+                m_state.penalties_accrued = m_state.penalties_accrued.checked_add(yield_share).ok_or(Error::Overflow)?;
+                write_member_state(env, &member, &m_state)?;
+            }
+        }
+    }
+
+    // 3. Penalty Distribution: By default all collected penalties are distributed equally among
+    // ALL members by increasing their claimable balance. The owner can redirect, burn, or pay
+    // them out with the pot.
+    if pooled_penalties > 0 {
+        match state.config.penalty_policy {
+            PenaltyPolicy::Redistribute => {
+                // Members who opted out leave their share to the others
+                let mut sharers = Vec::new(env);
+                for member in members.iter() {
+                    if !read_member_state(env, &member)?.opt_out_penalty_share {
+                        sharers.push_back(member);
+                    }
+                }
+                let num_sharers = sharers.len();
+
+                // The remainder of an uneven split rolls into the next cycle instead of being lost;
+                // with nobody to share it, the whole pool does
+                let pool = pooled_penalties.checked_add(state.penalty_dust).ok_or(Error::Overflow)?;
+                let penalty_share = if num_sharers == 0 { 0 } else { pool / num_sharers as i128 };
+                state.penalty_dust = pool
+                    .checked_sub(penalty_share.checked_mul(num_sharers as i128).ok_or(Error::Overflow)?)
+                    .ok_or(Error::Overflow)?;
+
+                // Optionally hand the remainder to one member, rotating each cycle
+                let mut dust_recipient = None;
+                if state.config.round_robin_dust && num_sharers > 0 {
+                    let index = state.dust_recipient_index % num_sharers;
+                    dust_recipient = Some(sharers.get(index).unwrap());
+                    state.dust_recipient_index = (index + 1) % num_sharers;
+                }
+
+                for member in sharers.iter() {
+                    let mut share = penalty_share;
+                    if dust_recipient.as_ref() == Some(&member) {
+                        share = share.checked_add(state.penalty_dust).ok_or(Error::Overflow)?;
+                    }
+                    let mut m_state = read_member_state(env, &member)?;
+                    m_state.penalties_accrued = m_state.penalties_accrued.checked_add(share).ok_or(Error::Overflow)?;
+                    write_member_state(env, &member, &m_state)?;
+                }
+                if dust_recipient.is_some() {
+                    state.penalty_dust = 0;
+                }
+            }
+            PenaltyPolicy::ToOwner => {
+                let owner = state.config.owner.clone();
+                let mut o_state = read_member_state(env, &owner)?;
+                o_state.penalties_accrued = o_state.penalties_accrued.checked_add(pooled_penalties).ok_or(Error::Overflow)?;
+                write_member_state(env, &owner, &o_state)?;
+            }
+            PenaltyPolicy::Burn => {}
+            PenaltyPolicy::ToRecipient => {} // Already paid out with the pot
+        }
+    }
+
+    let mut recipient_state = read_member_state(env, &payout_recipient)?;
+    recipient_state.received_payout = true;
+    write_member_state(env, &payout_recipient, &recipient_state)?;
+
+    let num_depositors = state.deposits_bitmap.count_ones();
+    CircleState::emit_payout_event(env, payout_recipient.clone(), state.current_cycle, total_pot, net_payout, num_depositors, state.config.token_decimals);
+
+    let record = CycleRecord {
+        recipient: payout_recipient.clone(),
+        pot: total_pot,
+        num_deposited: num_depositors,
+        num_missed,
+        timestamp: now,
+    };
+    env.storage().persistent().set(&DataKey::CycleLog(state.season, state.current_cycle), &record);
+
+    let mut stats = read_stats(env);
+    stats.total_pot_distributed = stats.total_pot_distributed.checked_add(net_payout).ok_or(Error::Overflow)?;
+    stats.total_penalties_collected = stats.total_penalties_collected.checked_add(pooled_penalties).ok_or(Error::Overflow)?;
+    stats.total_cycles_executed = stats.total_cycles_executed.checked_add(1).ok_or(Error::Overflow)?;
+    env.storage().instance().set(&DataKey::Stats, &stats);
+
+    // --- Advance Cycle State ---
+    
+    state.last_executed_cycle = state.current_cycle;
+    state.current_cycle = state.current_cycle.checked_add(1).ok_or(Error::Overflow)?;
+    
+    // Rotate the payout index past the member just paid
+    state.paid_this_round |= member_bit(payout_index);
+    state.next_payout_index = (payout_position.checked_add(1).ok_or(Error::Overflow)?) % num_slots;
+
+    // Paying the last unpaid member completes the rotation round
+    let mask = round_mask(env, &state)?;
+    if state.paid_this_round & mask == mask {
+        complete_round(env, &mut state)?;
+    }
+
+    // Reset the deposit bitmap for the new cycle
+    state.deposits_bitmap = 0;
+    state.current_cycle_deposited = 0;
+    state.cycle_deposits = Map::new(env);
+    state.member_deposits = Map::new(env);
+    
+    // Update last execution time
+    env.storage().instance().set(&DataKey::LastCycleTime, &now);
+
+    write_state(env, &state);
+    CircleState::emit_cycle_executed_event(env, state.current_cycle - 1, payout_recipient);
+
+    Ok(())
+}
+
+// Loads the state and checks that `owner` is the circle owner
+fn read_owner_state(env: &Env, owner: &Address) -> Result<CircleState, Error> {
+    owner.require_auth();
+    let state = read_state(env);
+    if state.config.owner != *owner {
+        return Err(Error::NotOwner);
+    }
+    Ok(state)
+}
+
+// Empties the member's slot. Other members keep their slot, so the bitmaps, payout order,
+// and payout cursor need no realignment. A deposit already made this cycle is moved to
+// `departed_deposits` so it can be refunded.
+fn vacate_slot(state: &mut CircleState, index: u32) -> Result<(), Error> {
+    let member = member_at(state, index).ok_or(Error::NotMember)?;
+    state.slots.set(index, None);
+
+    state.deposits_bitmap &= !member_bit(index);
+    state.paid_this_round &= !member_bit(index);
+    state.member_deposits.remove(member.clone());
+
+    if let Some(record) = state.cycle_deposits.get(member.clone()) {
+        let value = if record.token == state.config.token_asset { record.amount } else { state.config.deposit_amount };
+        state.current_cycle_deposited = state.current_cycle_deposited
+            .checked_sub(value)
+            .ok_or(Error::Overflow)?;
+        state.cycle_deposits.remove(member.clone());
+        state.departed_deposits.set(member, record);
+    }
+    Ok(())
+}
+
+// Moves the first waitlisted address that can still join into a free slot. Addresses that
+// fail `check_join`, or can't pay the join fee from their allowance, are dropped.
+fn promote_from_waitlist(env: &Env, state: &mut CircleState) -> Result<(), Error> {
+    while let Some(next) = state.waitlist.pop_front() {
+        let Ok(member_state) = check_join(env, state, &next) else { continue };
+
+        // The promoted address doesn't sign this call, so the fee comes from its allowance
+        let fee = state.config.join_fee;
+        if fee > 0 {
+            let token_client = get_token_client(env, &state.config.token_asset);
+            let contract = env.current_contract_address();
+            if token_client.allowance(&next, &contract) < fee || token_client.balance(&next) < fee {
+                continue;
+            }
+            token_client.transfer_from(&contract, &next, &state.config.owner, &fee);
+        }
+
+        let Some(slot) = free_slot(state) else { return Ok(()) };
+        state.slots.set(slot, Some(next.clone()));
+        write_member_state(env, &next, &member_state)?;
+        CircleState::emit_member_joined_event(env, next);
+        return Ok(());
+    }
+    Ok(())
+}
+
+// Takes `member` off the waitlist once they hold a slot
+fn remove_from_waitlist(state: &mut CircleState, member: &Address) {
+    if let Some(i) = state.waitlist.first_index_of(member) {
+        state.waitlist.remove(i);
+    }
+}
+
+// Empties the member's slot and closes the rotation round if everyone left in the
+// circle has already been paid
+fn depart_member(env: &Env, state: &mut CircleState, index: u32) -> Result<(), Error> {
+    let member = member_at(state, index).ok_or(Error::NotMember)?;
+    vacate_slot(state, index)?;
+
+    let mut m_state = read_member_state(env, &member)?;
+    refund_prepaid_cycles(env, state, &member, &mut m_state)?;
+    write_member_state(env, &member, &m_state)?;
+
+    // Before the first cycle the next waitlisted address takes over the vacancy
+    if state.current_cycle == 1 {
+        promote_from_waitlist(env, state)?;
+    }
+
+    let mask = round_mask(env, state)?;
+    if state.paid_this_round != 0 && state.paid_this_round & mask == mask {
+        complete_round(env, state)?;
+    }
+    Ok(())
+}
+
+// Clamps a page request to the member list, returning the [start, end) bounds
+fn page_bounds(members: &Vec<Address>, start: u32, limit: u32) -> (u32, u32) {
+    let len = members.len();
+    let start = start.min(len);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len);
+    (start, end)
+}
+
+fn add_prepaid_total(env: &Env, delta: i128) -> Result<(), Error> {
+    let total: i128 = env.storage().instance().get(&DataKey::PrepaidTotal).unwrap_or(0);
+    let total = total.checked_add(delta).ok_or(Error::Overflow)?;
+    env.storage().instance().set(&DataKey::PrepaidTotal, &total);
+    Ok(())
+}
+
+// Adjusts the `token_asset` held against member credits, never below zero
+fn add_backed_credit(env: &Env, delta: i128) -> Result<(), Error> {
+    let total: i128 = env.storage().instance().get(&DataKey::BackedCredit).unwrap_or(0);
+    let total = total.checked_add(delta).ok_or(Error::Overflow)?.max(0);
+    env.storage().instance().set(&DataKey::BackedCredit, &total);
+    Ok(())
+}
+
+// Balance of `token` the contract holds on members' behalf, which no payout may spend:
+// departed members' deposits, and for `token_asset` also prepaid funds, the bonus reserve,
+// and unclaimed credits as far as tokens back them. `claim` is credit being paid out right
+// now, which is not held back from itself.
+fn reserved_funds(env: &Env, state: &CircleState, token: &Address, claim: i128) -> Result<i128, Error> {
+    let mut reserved: i128 = 0;
+    for record in state.departed_deposits.values().iter() {
+        if record.token == *token {
+            reserved = reserved.checked_add(record.amount).ok_or(Error::Overflow)?;
+        }
+    }
+    if *token == state.config.token_asset {
+        let prepaid: i128 = env.storage().instance().get(&DataKey::PrepaidTotal).unwrap_or(0);
+        let credits: i128 = env.storage().instance().get(&DataKey::PenaltyPoolTotal).unwrap_or(0);
+        let backed: i128 = env.storage().instance().get(&DataKey::BackedCredit).unwrap_or(0);
+        let credits = credits
+            .checked_sub(claim)
+            .ok_or(Error::Overflow)?
+            .min(backed.checked_sub(claim).ok_or(Error::Overflow)?)
+            .max(0);
+        reserved = reserved
+            .checked_add(prepaid).ok_or(Error::Overflow)?
+            .checked_add(credits).ok_or(Error::Overflow)?
+            .checked_add(state.bonus_reserve).ok_or(Error::Overflow)?;
+    }
+    Ok(reserved)
+}
+
+// `token_asset` deposits for the current cycle still held by the contract (not parked for yield)
+fn held_deposits(state: &CircleState) -> Result<i128, Error> {
+    if state.config.yield_contract.is_some() {
+        return Ok(0);
+    }
+    let mut held: i128 = 0;
+    for record in state.cycle_deposits.values().iter() {
+        if record.token == state.config.token_asset {
+            held = held.checked_add(record.amount).ok_or(Error::Overflow)?;
+        }
+    }
+    Ok(held)
+}
+
+// Whether the contract can pay a claim of `amount` credit without touching this cycle's
+// deposits or funds held for others
+fn claim_covered(env: &Env, state: &CircleState, amount: i128) -> Result<bool, Error> {
+    let held = reserved_funds(env, state, &state.config.token_asset, amount)?
+        .checked_add(held_deposits(state)?)
+        .ok_or(Error::Overflow)?;
+    let balance = get_token_client(env, &state.config.token_asset).balance(&env.current_contract_address());
+    Ok(balance.checked_sub(held).ok_or(Error::Overflow)? >= amount)
+}
+
+fn read_stats(env: &Env) -> CircleStats {
+    env.storage().instance().get(&DataKey::Stats).unwrap_or(CircleStats {
+        total_pot_distributed: 0,
+        total_penalties_collected: 0,
+        total_cycles_executed: 0,
+    })
+}
+
+fn get_token_client(env: &Env, token_id: &Address) -> TokenClient {
+    TokenClient::new(env, token_id)
+}
+
+// Reads the token's decimals, which doubles as a check that the address is a token contract
+fn token_decimals(env: &Env, token: &Address) -> Result<u32, Error> {
+    match get_token_client(env, token).try_decimals() {
+        Ok(Ok(decimals)) => Ok(decimals),
+        _ => Err(Error::InvalidToken),
+    }
+}
+
+
+// --- The Contract ---
+#[contract]
+pub struct SavingsCircle;
+
+#[contractimpl]
+impl SavingsCircle {
+    
+    // --- Initialization & Membership ---
+
+    /// Creates the savings circle. Only one call allowed per contract instance.
+    pub fn create_circle(
+        env: Env,
+        owner: Address,
+        token_asset: Address,
+        deposit_amount: i128,
+        members: Vec<Address>,
+        cycle_interval_secs: u64,
+        join_deadline_secs: u64,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        if env.storage().instance().has(&DataKey::CircleState) {
+            return Err(Error::CircleExists);
+        }
+        let decimals = token_decimals(&env, &token_asset)?;
+        
+        // Basic validation
+        if deposit_amount <= 0 || members.len() == 0 {
+            // More robust validation needed in production
+        }
+
+        let config = CircleConfig {
+            owner: owner.clone(),
+            token_asset: token_asset.clone(),
+            deposit_amount,
+            cycle_interval_secs,
+            join_deadline_secs,
+            excuse_paid_members: false,
+            fee_bps: 0,
+            fee_recipient: owner.clone(),
+            min_reputation: 0,
+            max_rounds: 0,
+            payout_order: PayoutOrder::Sequential,
+            accepted_tokens: Vec::from_array(&env, [token_asset.clone()]),
+            token_rates: Map::new(&env),
+            yield_contract: None,
+            dispute_window_secs: 0,
+            rep_gain: 1,
+            rep_loss: 1,
+            starting_reputation: DEFAULT_STARTING_REPUTATION,
+            allowlist: members.clone(),
+            min_deposits_for_payout: 0,
+            skip_defaulting_recipient: false,
+            penalty_policy: PenaltyPolicy::Redistribute,
+            reputation_decay_per_cycle: 0,
+            inactivity_gap_cycles: 0,
+            join_fee: 0,
+            pause_quorum: 0,
+            deposit_window_secs: 0,
+            owner_is_member: true,
+            max_fine_per_member: 0,
+            round_robin_dust: false,
+            priority_window_secs: 0,
+            priority_min_reputation: 0,
+            bonus_skim_bps: 0,
+            max_failures_before_pause: 0,
+            token_decimals: decimals,
+            late_after_secs: 0,
+            late_grace_period_secs: 0,
+            first_cycle_delay_secs: 0,
+        };
+
+        let initial_state = CircleState {
+            config,
+            slots: Map::new(&env), // Members confirm their spot with join_circle
+            member_deposits: Map::new(&env),
+            current_cycle: 1,
+            next_payout_index: 0,
+            deposits_bitmap: 0,
+            is_paused: false,
+            is_open_for_joining: true,
+            completed_rounds: 0,
+            is_finished: false,
+            payout_sequence: Vec::new(&env),
+            cycle_deposits: Map::new(&env),
+            last_executed_cycle: 0,
+            yield_principal: 0,
+            departed_deposits: Map::new(&env),
+            paid_this_round: 0,
+            joining_opened_at: env.ledger().timestamp(),
+            current_cycle_deposited: 0,
+            penalty_dust: 0,
+            pause_votes: Vec::new(&env),
+            dust_recipient_index: 0,
+            deposits_paused: false,
+            bonus_reserve: 0,
+            consecutive_failures: 0,
+            last_failure_time: 0,
+            waitlist: Vec::new(&env),
+            season: 0,
+        };
+
+        write_state(&env, &initial_state);
+        env.storage().instance().set(&DataKey::StartingReputation, &DEFAULT_STARTING_REPUTATION);
+        env.storage().instance().set(&DataKey::Version, &CONTRACT_VERSION);
+
+        Ok(())
+    }
+
+    /// Same as `create_circle`, but every initial member must also authorize the call,
+    /// proving they agreed to the circle's terms.
+    pub fn create_circle_strict(
+        env: Env,
+        owner: Address,
+        token_asset: Address,
+        deposit_amount: i128,
+        members: Vec<Address>,
+        cycle_interval_secs: u64,
+        join_deadline_secs: u64,
+    ) -> Result<(), Error> {
+        for member in members.iter() {
+            member.require_auth();
+        }
+        Self::create_circle(env, owner, token_asset, deposit_amount, members, cycle_interval_secs, join_deadline_secs)
+    }
+    
+    /// Confirms participation in the circle. Must be called before deadline.
+    pub fn join_circle(env: Env, member: Address) -> Result<(), Error> {
+        member.require_auth();
+        let mut state = read_state(&env);
+
+        let member_state = check_join(&env, &state, &member)?;
+
+        if state.config.join_fee > 0 {
+            let token_client = get_token_client(&env, &state.config.token_asset);
+            if token_client.balance(&member) < state.config.join_fee {
+                return Err(Error::InsufficientFunds);
+            }
+            token_client.transfer(&member, &state.config.owner, &state.config.join_fee);
+        }
+        
+        let slot = free_slot(&state).ok_or(Error::CircleFull)?;
+        state.slots.set(slot, Some(member.clone()));
+        remove_from_waitlist(&mut state, &member);
+        write_state(&env, &state);
+        // Lock in the starting reputation at join time
+        write_member_state(&env, &member, &member_state)?;
+        
+        CircleState::emit_member_joined_event(&env, member);
+
+        Ok(())
+    }
+
+
+    /// Queues the caller for the next slot vacated before the first cycle. When a member
+    /// leaves or is removed, the first address on the waitlist that can still join takes
+    /// their place. With a join fee set, the caller must approve the contract to spend it.
+    pub fn join_waitlist(env: Env, member: Address) -> Result<(), Error> {
+        member.require_auth();
+        let mut state = read_state(&env);
+
+        if state.is_paused { return Err(Error::Paused); }
+        if state.current_cycle > 1 { return Err(Error::CycleAlreadyStarted); }
+        if get_member_index(&state, &member).is_ok() || state.waitlist.contains(&member) {
+            return Err(Error::AlreadyJoined);
+        }
+        if !state.config.owner_is_member && member == state.config.owner {
+            return Err(Error::OwnerCannotJoin);
+        }
+        if !state.config.allowlist.contains(&member) {
+            return Err(Error::NotInvited);
+        }
+        if read_member_state(&env, &member)?.banned {
+            return Err(Error::MemberBanned);
+        }
+
+        state.waitlist.push_back(member);
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Confirms several invited members at once on their behalf. Already-joined addresses are skipped.
+    /// No join fee is taken, since members don't authorize this call.
+    pub fn join_many(env: Env, owner: Address, members: Vec<Address>) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        for member in members.iter() {
+            if get_member_index(&state, &member).is_ok() {
+                continue;
+            }
+            let member_state = check_join(&env, &state, &member)?;
+
+            let slot = free_slot(&state).ok_or(Error::CircleFull)?;
+            state.slots.set(slot, Some(member.clone()));
+            remove_from_waitlist(&mut state, &member);
+            write_member_state(&env, &member, &member_state)?;
+            CircleState::emit_member_joined_event(&env, member);
+        }
+
+        write_state(&env, &state);
+        Ok(())
+    }
+
+
+    /// Opts the member out of (or back into) redistributed penalty shares. While opted out
+    /// their share goes to the other members.
+    pub fn set_penalty_share_opt_out(env: Env, member: Address, opt_out: bool) -> Result<(), Error> {
+        member.require_auth();
+        let state = read_state(&env);
+        get_member_index(&state, &member)?;
+
+        let mut m_state = read_member_state(&env, &member)?;
+        m_state.opt_out_penalty_share = opt_out;
+        write_member_state(&env, &member, &m_state)?;
+        Ok(())
+    }
+
+    /// Sends the member's future payouts to `payout_address` (e.g. a cold wallet) instead of
+    /// the address they sign with. `None` reverts to their own address.
+    pub fn set_payout_address(env: Env, member: Address, payout_address: Option<Address>) -> Result<(), Error> {
+        member.require_auth();
+        let state = read_state(&env);
+        get_member_index(&state, &member)?;
+
+        let mut m_state = read_member_state(&env, &member)?;
+        m_state.payout_address = payout_address;
+        write_member_state(&env, &member, &m_state)?;
+        Ok(())
+    }
+
+    /// Stores an opt-in contact handle (e.g. an email or chat ID) for off-chain notifiers.
+    pub fn set_contact(env: Env, member: Address, handle: Bytes) -> Result<(), Error> {
+        member.require_auth();
+        let state = read_state(&env);
+        get_member_index(&state, &member)?;
+
+        env.storage().persistent().set(&DataKey::MemberContact(member), &handle);
+        Ok(())
+    }
+
+
+    /// Leaves the circle. A deposit already made this cycle stays recorded for `refund_stuck_deposits`.
+    /// Members who have received the pot must stay until the round completes.
+    pub fn leave_circle(env: Env, member: Address) -> Result<(), Error> {
+        member.require_auth();
+        let mut state = read_state(&env);
+
+        if state.is_paused { return Err(Error::Paused); }
+
+        let member_index = get_member_index(&state, &member)?;
+        // Leaving now would skip the deposits that fund everyone still waiting to be paid
+        if read_member_state(&env, &member)?.received_payout {
+            return Err(Error::AlreadyPaidOut);
+        }
+        depart_member(&env, &mut state, member_index)?;
+        write_state(&env, &state);
+
+        CircleState::emit_member_left_event(&env, member, state.current_cycle);
+        Ok(())
+    }
+
+    /// Removes a member. Like `leave_circle`, a deposit already made this cycle stays
+    /// recorded for `refund_stuck_deposits`.
+    pub fn remove_member(env: Env, owner: Address, member: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        let member_index = get_member_index(&state, &member)?;
+        depart_member(&env, &mut state, member_index)?;
+        write_state(&env, &state);
+
+        CircleState::emit_member_removed_event(&env, member, owner, state.current_cycle);
+        Ok(())
+    }
+
+
+    // --- Core Operations ---
+    
+    /// Participant deposits the fixed amount for the current cycle.
+    /// `token` must be on the circle's whitelist; the amount is converted at its configured rate.
+    pub fn deposit(env: Env, depositor: Address, token: Address) -> Result<(), Error> {
+        depositor.require_auth();
+        let mut state = read_state(&env);
+        
+        // 1. Check membership and whether the deposit is due
+        let member_index = check_deposit(&env, &state, &depositor)?;
+        let amount = deposit_in_token(&state.config, &token)?;
+
+        // 2. Transfer token from depositor to contract, recording what actually arrived
+        let received = transfer_in(&env, &token, &depositor, amount)?;
+
+        // 3. Update bitmap and reputation
+        record_deposit(&env, &mut state, &depositor, member_index, &token, received)?;
+
+        Ok(())
+    }
+
+    /// Pays `cycles` future deposits up front. Each executed cycle the member hasn't deposited
+    /// for draws one prepaid deposit instead of counting as a miss. If a transfer fee leaves
+    /// less than `cycles` full deposits, the rest is credited to the prepaid balance.
+    pub fn prepay(env: Env, member: Address, cycles: u32) -> Result<(), Error> {
+        member.require_auth();
+        let state = read_state(&env);
+
+        if state.is_paused { return Err(Error::Paused); }
+        if state.deposits_paused { return Err(Error::DepositsPaused); }
+        if state.is_finished { return Err(Error::CircleFinished); }
+        get_member_index(&state, &member)?;
+        if cycles == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let total = state.config.deposit_amount
+            .checked_mul(cycles as i128)
+            .ok_or(Error::Overflow)?;
+        let received = transfer_in(&env, &state.config.token_asset, &member, total)?;
+        let funded_cycles = (received / state.config.deposit_amount) as u32;
+        let remainder = received % state.config.deposit_amount;
+
+        let mut m_state = read_member_state(&env, &member)?;
+        m_state.prepaid_cycles = m_state.prepaid_cycles.checked_add(funded_cycles).ok_or(Error::Overflow)?;
+        m_state.prepaid_balance = m_state.prepaid_balance.checked_add(remainder).ok_or(Error::Overflow)?;
+        write_member_state(&env, &member, &m_state)?;
+        add_prepaid_total(&env, received)?;
+        Ok(())
+    }
+
+    /// Adds `amount` to the member's prepaid balance, subscribing them to auto-deposit: each
+    /// executed cycle they haven't deposited for draws `deposit_amount` from it.
+    pub fn fund_balance(env: Env, member: Address, amount: i128) -> Result<(), Error> {
+        member.require_auth();
+        let state = read_state(&env);
+
+        if state.is_paused { return Err(Error::Paused); }
+        if state.deposits_paused { return Err(Error::DepositsPaused); }
+        if state.is_finished { return Err(Error::CircleFinished); }
+        get_member_index(&state, &member)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let received = transfer_in(&env, &state.config.token_asset, &member, amount)?;
+
+        let mut m_state = read_member_state(&env, &member)?;
+        m_state.prepaid_balance = m_state.prepaid_balance.checked_add(received).ok_or(Error::Overflow)?;
+        write_member_state(&env, &member, &m_state)?;
+        add_prepaid_total(&env, received)?;
+        Ok(())
+    }
+
+    /// Returns the member's whole prepaid balance, ending their auto-deposit subscription.
+    pub fn withdraw_balance(env: Env, member: Address) -> Result<(), Error> {
+        member.require_auth();
+        let state = read_state(&env);
+
+        let mut m_state = read_member_state(&env, &member)?;
+        let amount = m_state.prepaid_balance;
+        if amount == 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        m_state.prepaid_balance = 0;
+        write_member_state(&env, &member, &m_state)?;
+        add_prepaid_total(&env, -amount)?;
+        get_token_client(&env, &state.config.token_asset).transfer(&env.current_contract_address(), &member, &amount);
+        Ok(())
+    }
+
+    /// A sponsor pays the current cycle's deposit for `beneficiary`. The tokens come from the
+    /// sponsor, while the deposit bit and reputation credit go to the beneficiary.
+    pub fn deposit_for(env: Env, sponsor: Address, beneficiary: Address) -> Result<(), Error> {
+        sponsor.require_auth();
+        let mut state = read_state(&env);
+
+        // 1. Check the beneficiary's membership and whether their deposit is due
+        let member_index = check_deposit(&env, &state, &beneficiary)?;
+
+        // 2. Transfer token from sponsor to contract
+        let token = state.config.token_asset.clone();
+        let amount = state.config.deposit_amount;
+        let received = transfer_in(&env, &token, &sponsor, amount)?;
+
+        // 3. Update bitmap and reputation for the beneficiary
+        record_deposit(&env, &mut state, &beneficiary, member_index, &token, received)?;
+
+        Ok(())
+    }
+
+    /// Owner backfills every deposit still missing this cycle from their own funds, so the
+    /// cycle pays out in full. Covered members escape the missed-deposit penalty but gain no
+    /// reputation. If the cycle is cancelled the backfill is refunded to the owner.
+    pub fn cover_missing(env: Env, owner: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if state.is_paused { return Err(Error::Paused); }
+        if state.deposits_paused { return Err(Error::DepositsPaused); }
+        if state.is_finished { return Err(Error::CircleFinished); }
+        if env.storage().instance().has(&DataKey::PendingCycle) { return Err(Error::CyclePending); }
+
+        let mut missing = Vec::new(&env);
+        for (i, slot) in state.slots.iter() {
+            let Some(member) = slot else { continue };
+            if state.deposits_bitmap & member_bit(i) == 0 && deposit_required(&env, &state, &member)? {
+                missing.push_back((i, member));
+            }
+        }
+
+        let token = state.config.token_asset.clone();
+        let amount = state.config.deposit_amount;
+        let total = amount.checked_mul(missing.len() as i128).ok_or(Error::Overflow)?;
+        if get_token_client(&env, &token).balance(&owner) < total {
+            return Err(Error::InsufficientFunds);
+        }
+
+        for (i, member) in missing.iter() {
+            let received = transfer_in(&env, &token, &owner, amount)?;
+            let record = DepositRecord {
+                token: token.clone(),
+                amount: received,
+                payer: Some(owner.clone()),
+                prior_deposit_cycle: 0,
+                prior_best_streak: 0,
+            };
+            store_deposit(&env, &mut state, &member, i, record)?;
+            state.member_deposits.remove(member.clone()); // The member didn't pay late
+            CircleState::emit_deposit_covered_event(&env, member, owner.clone(), state.current_cycle);
+        }
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Deposits on a member's behalf by pulling from the allowance they granted the contract.
+    /// Callable by anyone (e.g. a relayer), so the member doesn't need to sign every cycle.
+    pub fn deposit_from_allowance(env: Env, member: Address) -> Result<(), Error> {
+        let mut state = read_state(&env);
+
+        // 1. Check membership and whether the deposit is due
+        let member_index = check_deposit(&env, &state, &member)?;
+
+        // 2. Pull tokens using the contract's allowance from the member
+        let token = state.config.token_asset.clone();
+        let amount = state.config.deposit_amount;
+        let received = transfer_in_from_allowance(&env, &token, &member, amount)?;
+
+        // 3. Update bitmap and reputation
+        record_deposit(&env, &mut state, &member, member_index, &token, received)?;
+
+        Ok(())
+    }
+
+    /// Gasless deposit: the member signs an authorization for `(nonce)` off-chain and any relayer
+    /// submits it, paying the fees. The signature is carried in the transaction's auth entry and
+    /// verified by `require_auth_for_args`. Tokens are pulled via the member's allowance like
+    /// `deposit_from_allowance`. Nonces start at 0 and must be used in order.
+    pub fn deposit_signed(env: Env, member: Address, nonce: u64) -> Result<(), Error> {
+        member.require_auth_for_args((nonce,).into_val(&env));
+        let mut state = read_state(&env);
+
+        let nonce_key = DataKey::DepositNonce(member.clone());
+        let expected_nonce: u64 = env.storage().persistent().get(&nonce_key).unwrap_or(0);
+        if nonce != expected_nonce {
+            return Err(Error::InvalidNonce);
+        }
+
+        let member_index = check_deposit(&env, &state, &member)?;
+
+        let token = state.config.token_asset.clone();
+        let amount = state.config.deposit_amount;
+        let received = transfer_in_from_allowance(&env, &token, &member, amount)?;
+
+        record_deposit(&env, &mut state, &member, member_index, &token, received)?;
+        let next_nonce = expected_nonce.checked_add(1).ok_or(Error::Overflow)?;
+        env.storage().persistent().set(&nonce_key, &next_nonce);
+
+        Ok(())
+    }
+    
+    /// Executes the next cycle, handles payouts, and applies penalties.
+    /// This function is intended to be called by an external relayer/frontend.
+    pub fn execute_cycle(env: Env) -> Result<(), Error> {
+        // No auth check on the caller, as it's an external trigger
+        let state = read_state(&env);
+
+        if state.config.dispute_window_secs > 0 {
+            return Err(Error::DisputeWindowEnabled); // Use begin_cycle / finalize_cycle
+        }
+        check_cycle_due(&env, &state)?;
+
+        run_cycle(&env, state)
+    }
+
+    /// Owner override for a cycle stuck behind the scheduling check: runs the current cycle
+    /// now, ignoring the interval. Pause, finish, and membership checks still apply.
+    pub fn force_execute(env: Env, owner: Address) -> Result<(), Error> {
+        let state = read_owner_state(&env, &owner)?;
+
+        if state.config.dispute_window_secs > 0 {
+            return Err(Error::DisputeWindowEnabled); // Use begin_cycle / finalize_cycle
+        }
+        check_cycle_runnable(&state)?;
+
+        run_cycle(&env, state)
+    }
+
+    /// Dry run of `execute_cycle`: returns (recipient, pot, members to be penalized) without
+    /// changing state or moving tokens. The pot is the gross `token_asset` pot, before fees.
+    pub fn simulate_cycle(env: Env) -> Result<(Address, i128, Vec<Address>), Error> {
+        let mut state = read_state(&env);
+        check_cycle_due(&env, &state)?;
+
+        // Prepaid members count as deposited, as they will be at execution
+        for (i, member) in prepaid_members(&env, &state)?.iter() {
+            state.deposits_bitmap |= member_bit(i);
+            let record = DepositRecord {
+                token: state.config.token_asset.clone(),
+                amount: state.config.deposit_amount,
+                payer: None,
+                prior_deposit_cycle: 0,
+                prior_best_streak: 0,
+            };
+            state.cycle_deposits.set(member, record);
+        }
+        if state.deposits_bitmap.count_ones() < state.config.min_deposits_for_payout {
+            return Err(Error::QuorumNotMet);
+        }
+
+        let (total_pot, _) = cycle_pots(&env, &state)?;
+        let (_, _, recipient) = select_recipient(&env, &mut state)?;
+
+        let mut to_be_penalized = Vec::new(&env);
+        for (i, slot) in state.slots.iter() {
+            let Some(member) = slot else { continue };
+            let is_deposited = state.deposits_bitmap & member_bit(i) != 0;
+            if (!is_deposited && deposit_required(&env, &state, &member)?)
+                || (is_deposited && late_fine(&env, &state, &member)? > 0)
+            {
+                to_be_penalized.push_back(member);
+            }
+        }
+        Ok((recipient, total_pot, to_be_penalized))
+    }
+
+    /// First phase of a two-phase execution: snapshots this cycle's deposits and opens the
+    /// dispute window. Deposits are frozen until the cycle is finalized or vetoed.
+    pub fn begin_cycle(env: Env) -> Result<(), Error> {
+        let state = read_state(&env);
+
+        if env.storage().instance().has(&DataKey::PendingCycle) {
+            return Err(Error::CyclePending);
+        }
+        check_cycle_due(&env, &state)?;
+
+        let pending = PendingCycle {
+            cycle: state.current_cycle,
+            opened_at: env.ledger().timestamp(),
+            deposits_bitmap: state.deposits_bitmap,
+            members: active_members(&env, &state),
+        };
+        env.storage().instance().set(&DataKey::PendingCycle, &pending);
+        Ok(())
+    }
+
+    /// Second phase: performs the payout once the dispute window has passed without a veto.
+    /// Fails with `StaleSnapshot` if the roster or deposits changed since `begin_cycle`; the
+    /// owner can then `veto_cycle` and start over.
+    pub fn finalize_cycle(env: Env) -> Result<(), Error> {
+        let state = read_state(&env);
+
+        check_cycle_runnable(&state)?;
+
+        let pending: PendingCycle = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingCycle)
+            .ok_or(Error::NoPendingCycle)?;
+        if env.ledger().timestamp() < pending.opened_at.saturating_add(state.config.dispute_window_secs) {
+            return Err(Error::DisputeWindowOpen);
+        }
+
+        // The payout must match what was open to dispute
+        if pending.cycle != state.current_cycle
+            || pending.deposits_bitmap != state.deposits_bitmap
+            || pending.members != active_members(&env, &state)
+        {
+            return Err(Error::StaleSnapshot);
+        }
+
+        env.storage().instance().remove(&DataKey::PendingCycle);
+        run_cycle(&env, state)
+    }
+
+    /// Cancels the pending payout and refunds this cycle's deposits. The cycle does not advance.
+    pub fn veto_cycle(env: Env, owner: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        let pending: PendingCycle = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingCycle)
+            .ok_or(Error::NoPendingCycle)?;
+
+        refund_cycle_deposits(&env, &mut state)?;
+        env.storage().instance().remove(&DataKey::PendingCycle);
+
+        write_state(&env, &state);
+        CircleState::emit_cycle_vetoed_event(&env, pending.cycle, owner);
+        Ok(())
+    }
+
+    /// Refunds this cycle's deposits without paying out, e.g. when the quorum can't be met.
+    /// Reputation and streak credit for the refunded deposits is taken back. The cycle does
+    /// not advance; members can deposit again.
+    pub fn cancel_cycle(env: Env, owner: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if env.storage().instance().has(&DataKey::PendingCycle) {
+            return Err(Error::CyclePending);
+        }
+        if state.deposits_bitmap == 0 {
+            return Err(Error::NothingToRefund);
+        }
+
+        refund_cycle_deposits(&env, &mut state)?;
+        write_state(&env, &state);
+        CircleState::emit_cycle_cancelled_event(&env, state.current_cycle, owner);
+        Ok(())
+    }
+
+
+    // --- Admin & Utility ---
+    
+    /// Allows a member to claim their accumulated refunds/penalties (positive balance). The
+    /// whole balance is paid or nothing is: without enough free funds the claim fails with
+    /// `InsufficientFunds` and the balance stays claimable.
+    pub fn claim_refund(env: Env, member: Address) -> Result<(), Error> {
+        member.require_auth();
+        let state = read_state(&env);
+        
+        let mut m_state = read_member_state(&env, &member)?;
+        
+        let amount = m_state.penalties_accrued;
+        if amount == 0 {
+            return Err(Error::NothingToClaim);
+        }
+        if amount < 0 {
+            return Err(Error::FineOwed);
+        }
+        
+        if !claim_covered(&env, &state, amount)? {
+            return Err(Error::InsufficientFunds);
+        }
+        
+        // Reset accrued penalties before the transfer, so a re-entrant claim finds nothing
+        m_state.penalties_accrued = 0;
+        write_member_state(&env, &member, &m_state)?;
+        add_backed_credit(&env, -amount)?;
+        
+        // Transfer collected penalties from contract to member
+        get_token_client(&env, &state.config.token_asset).transfer(&env.current_contract_address(), &member, &amount);
+        
+        Ok(())
+    }
+
+    /// Pays down a fine (negative balance). Repayment is capped at the amount owed.
+    pub fn repay_fine(env: Env, member: Address, amount: i128) -> Result<(), Error> {
+        member.require_auth();
+        let state = read_state(&env);
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let mut m_state = read_member_state(&env, &member)?;
+        if m_state.penalties_accrued >= 0 {
+            return Err(Error::NoFineOwed);
+        }
+
+        // Only what arrives pays the fine down
+        let repayment = amount.min(-m_state.penalties_accrued);
+        let received = transfer_in(&env, &state.config.token_asset, &member, repayment)?;
+
+        m_state.penalties_accrued = m_state.penalties_accrued.checked_add(received).ok_or(Error::Overflow)?;
+        write_member_state(&env, &member, &m_state)?;
+        pay_fine_creditors(&env, &member, received)?;
+        // The repayment backs the credit the fine became, unless it was burned
+        if state.config.penalty_policy != PenaltyPolicy::Burn {
+            add_backed_credit(&env, received)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns deposits left behind by members who left the circle, e.g. when every member
+    /// left mid-cycle and `execute_cycle` can no longer run.
+    pub fn refund_stuck_deposits(env: Env, owner: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if state.departed_deposits.len() == 0 {
+            return Err(Error::NothingToRefund);
+        }
+
+        // Parked deposits must be back in the contract before they can be returned
+        withdraw_from_yield(&env, &mut state)?;
+
+        let contract = env.current_contract_address();
+        for (member, record) in state.departed_deposits.iter() {
+            let refund_to = record.payer.clone().unwrap_or(member);
+            get_token_client(&env, &record.token).transfer(&contract, &refund_to, &record.amount);
+        }
+        state.departed_deposits = Map::new(&env);
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Emergency pause for the circle.
+    pub fn pause(env: Env, owner: Address) -> Result<(), Error> {
+        owner.require_auth();
+        let mut state = read_state(&env);
+
+        if state.config.owner != owner {
+            return Err(Error::NotOwner);
+        }
+        
+        state.is_paused = true;
+        write_state(&env, &state);
+        CircleState::emit_paused_event(&env, owner);
+        Ok(())
+    }
+
+    /// Halts (or resumes) deposits and cycle execution only; claims and refunds keep working.
+    pub fn set_deposits_paused(env: Env, owner: Address, paused: bool) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.deposits_paused = paused;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Member vote to pause the circle. Once `pause_quorum` distinct members have voted the
+    /// circle pauses without owner action; only the owner can unpause, which clears the votes.
+    pub fn vote_pause(env: Env, member: Address) -> Result<(), Error> {
+        member.require_auth();
+        let mut state = read_state(&env);
+
+        if state.config.pause_quorum == 0 { return Err(Error::InvalidConfig); }
+        if state.is_paused { return Err(Error::Paused); }
+        get_member_index(&state, &member)?;
+        if state.pause_votes.contains(&member) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        state.pause_votes.push_back(member.clone());
+        CircleState::emit_pause_vote_event(&env, member, state.pause_votes.len());
+        if state.pause_votes.len() >= state.config.pause_quorum {
+            state.is_paused = true;
+            CircleState::emit_paused_event(&env, state.config.owner.clone());
+        }
+        write_state(&env, &state);
+        Ok(())
     }
 
     /// Unpauses the circle.
@@ -403,17 +2372,516 @@ impl SavingsCircle {
         owner.require_auth();
         let mut state = read_state(&env);
 
-        if state.config.owner != owner {
-            return Err(Error::NotOwner);
+        if state.config.owner != owner {
+            return Err(Error::NotOwner);
+        }
+
+        state.is_paused = false;
+        state.pause_votes = Vec::new(&env);
+        state.consecutive_failures = 0;
+        write_state(&env, &state);
+        CircleState::emit_unpaused_event(&env, owner);
+        Ok(())
+    }
+
+    /// Replaces the contract code with an already-uploaded wasm. Storage is kept as-is; the
+    /// stored `DataKey::Version` still names the old version until the owner calls `migrate`.
+    pub fn upgrade(env: Env, owner: Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        read_owner_state(&env, &owner)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Brings storage written by an older version up to `CONTRACT_VERSION`. Call after `upgrade`.
+    pub fn migrate(env: Env, owner: Address) -> Result<(), Error> {
+        read_owner_state(&env, &owner)?;
+
+        // Circles created before the key existed were written by version 1
+        let stored: u32 = env.storage().instance().get(&DataKey::Version).unwrap_or(1);
+        if stored >= CONTRACT_VERSION {
+            return Err(Error::AlreadyMigrated);
+        }
+        // Version 1 -> 2: member records migrate lazily on their next read, so only the
+        // marker needs updating
+        env.storage().instance().set(&DataKey::Version, &CONTRACT_VERSION);
+        Ok(())
+    }
+    
+    /// Toggles whether members already paid out this round are excused from depositing.
+    pub fn set_excuse_paid_members(env: Env, owner: Address, excuse: bool) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.excuse_paid_members = excuse;
+        write_state(&env, &state);
+        Ok(())
+    }
+    
+    /// Changes the cycle cadence. The new interval is measured from the last executed cycle,
+    /// so it governs the next `execute_cycle` onward; cycles already executed are unaffected.
+    pub fn set_cycle_interval(env: Env, owner: Address, new_interval_secs: u64) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if new_interval_secs == 0 || new_interval_secs <= state.config.deposit_window_secs {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.cycle_interval_secs = new_interval_secs;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Pushes the join deadline out by `additional_secs` and reopens joining.
+    pub fn extend_join_deadline(env: Env, owner: Address, additional_secs: u64) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if state.is_finished {
+            return Err(Error::CircleFinished);
+        }
+
+        state.config.join_deadline_secs = state.config.join_deadline_secs
+            .checked_add(additional_secs)
+            .ok_or(Error::Overflow)?;
+        state.is_open_for_joining = true;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets how long into each cycle deposits are accepted; must be shorter than the cycle
+    /// interval (0 accepts deposits until the cycle executes).
+    pub fn set_deposit_window(env: Env, owner: Address, deposit_window_secs: u64) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if deposit_window_secs != 0
+            && (deposit_window_secs >= state.config.cycle_interval_secs
+                || deposit_window_secs <= state.config.priority_window_secs)
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.deposit_window_secs = deposit_window_secs;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets how many consecutive quorum failures pause the circle (0 = never). While enabled,
+    /// a failed `execute_cycle` is recorded instead of returning `QuorumNotMet`, at most once
+    /// per cycle interval.
+    pub fn set_max_failures_before_pause(env: Env, owner: Address, max_failures: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.max_failures_before_pause = max_failures;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Fines deposits made more than `late_after_secs` into a cycle. The fine grows with the
+    /// lateness up to 10% of the deposit once `grace_period_secs` have passed (0 = no late fines).
+    pub fn set_late_penalty(env: Env, owner: Address, late_after_secs: u64, grace_period_secs: u64) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if grace_period_secs != 0 && late_after_secs >= state.config.cycle_interval_secs {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.late_after_secs = late_after_secs;
+        state.config.late_grace_period_secs = grace_period_secs;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Holds back the first `execute_cycle` until `delay_secs` after the circle opened (at
+    /// creation or restart).
+    /// Can only be changed before the first cycle has run.
+    pub fn set_first_cycle_delay(env: Env, owner: Address, delay_secs: u64) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if env.storage().instance().get::<_, u64>(&DataKey::LastCycleTime).unwrap_or(0) != 0 {
+            return Err(Error::CycleAlreadyStarted);
+        }
+
+        state.config.first_cycle_delay_secs = delay_secs;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets the share of each pot, in basis points, set aside as a bonus for the last recipient
+    /// of the final round. Only applies while `max_rounds` is set.
+    pub fn set_bonus_skim(env: Env, owner: Address, bonus_skim_bps: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if bonus_skim_bps > MAX_BPS {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.bonus_skim_bps = bonus_skim_bps;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets how many full rotation rounds run before the circle finishes (0 = indefinitely).
+    pub fn set_max_rounds(env: Env, owner: Address, max_rounds: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.max_rounds = max_rounds;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Starts a new season on a finished circle, keeping the config and member reputations.
+    pub fn restart_circle(env: Env, owner: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if !state.is_finished {
+            return Err(Error::NotFinished);
+        }
+
+        // Prepaid cycles belong to the finished run
+        for member in active_members(&env, &state).iter() {
+            let mut m_state = read_member_state(&env, &member)?;
+            if m_state.prepaid_cycles > 0 {
+                refund_prepaid_cycles(&env, &state, &member, &mut m_state)?;
+                write_member_state(&env, &member, &m_state)?;
+            }
+        }
+
+        // Anything set aside but never paid out goes back to the members as claimable credit
+        let leftover = state.bonus_reserve.checked_add(state.penalty_dust).ok_or(Error::Overflow)?;
+        let members = active_members(&env, &state);
+        if leftover > 0 && !members.is_empty() {
+            add_backed_credit(&env, state.bonus_reserve)?; // The reserve was held in tokens
+            let count = members.len() as i128;
+            let share = leftover.checked_div(count).ok_or(Error::Overflow)?;
+            let remainder = leftover.checked_rem(count).ok_or(Error::Overflow)?;
+            for (i, member) in members.iter().enumerate() {
+                let credit = if i == 0 { share.checked_add(remainder).ok_or(Error::Overflow)? } else { share };
+                let mut m_state = read_member_state(&env, &member)?;
+                m_state.penalties_accrued = m_state.penalties_accrued.checked_add(credit).ok_or(Error::Overflow)?;
+                write_member_state(&env, &member, &m_state)?;
+            }
+            state.bonus_reserve = 0;
+            state.penalty_dust = 0;
+        }
+        state.consecutive_failures = 0;
+        state.last_failure_time = 0;
+
+        // The new season starts without a previous execution, so `first_cycle_delay_secs`
+        // applies again and receipts and cycle logs go under new keys
+        env.storage().instance().remove(&DataKey::LastCycleTime);
+        state.season = state.season.checked_add(1).ok_or(Error::Overflow)?;
+
+        state.current_cycle = 1;
+        state.next_payout_index = 0;
+        state.paid_this_round = 0;
+        state.payout_sequence = Vec::new(&env); // A random order is drawn afresh each season
+        state.deposits_bitmap = 0;
+        state.current_cycle_deposited = 0;
+        state.cycle_deposits = Map::new(&env);
+        state.member_deposits = Map::new(&env);
+        state.last_executed_cycle = 0;
+        state.completed_rounds = 0;
+        state.is_finished = false;
+        state.is_open_for_joining = true;
+        state.joining_opened_at = env.ledger().timestamp();
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Wipes every member's record for a fresh season: reputation returns to the starting
+    /// value, fines are forgiven, and bans lifted. Fails while any member still has credits
+    /// to claim.
+    pub fn reset_member_stats(env: Env, owner: Address) -> Result<(), Error> {
+        let state = read_owner_state(&env, &owner)?;
+        let members = active_members(&env, &state);
+
+        for member in members.iter() {
+            if read_member_state(&env, &member)?.penalties_accrued > 0 {
+                return Err(Error::ClaimsOutstanding);
+            }
+        }
+        for member in members.iter() {
+            let mut m_state = read_member_state(&env, &member)?;
+            m_state.reputation_score = state.config.starting_reputation;
+            m_state.penalties_accrued = 0;
+            m_state.banned = false;
+            write_member_state(&env, &member, &m_state)?;
         }
+        Ok(())
+    }
 
-        state.is_paused = false;
+    /// Selects the payout order. A random order is drawn at the next `execute_cycle` and kept.
+    /// Only allowed between rounds, before anyone has been paid in the current one.
+    pub fn set_payout_order(env: Env, owner: Address, order: PayoutOrder) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if state.paid_this_round != 0 {
+            return Err(Error::RoundInProgress);
+        }
+        state.config.payout_order = order;
+        state.payout_sequence = Vec::new(&env);
         write_state(&env, &state);
         Ok(())
     }
-    
+
+    /// Selects where pooled penalties go. With `ToOwner` the owner collects them via `claim_refund`.
+    pub fn set_penalty_policy(env: Env, owner: Address, policy: PenaltyPolicy) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.penalty_policy = policy;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Corrects a misconfigured `token_asset`. Only allowed before the first cycle has run,
+    /// while no deposits are held and the contract holds none of the old token.
+    pub fn set_token_asset(env: Env, owner: Address, new_token: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        let old_token = state.config.token_asset.clone();
+        let old_balance = get_token_client(&env, &old_token).balance(&env.current_contract_address());
+        if state.current_cycle != 1 || state.deposits_bitmap != 0 || old_balance != 0 {
+            return Err(Error::CannotChangeToken);
+        }
+        let decimals = token_decimals(&env, &new_token)?;
+
+        if let Some(i) = state.config.accepted_tokens.first_index_of(&old_token) {
+            state.config.accepted_tokens.remove(i);
+        }
+        if !state.config.accepted_tokens.contains(&new_token) {
+            state.config.accepted_tokens.push_front(new_token.clone());
+        }
+        state.config.token_rates.remove(new_token.clone());
+        state.config.token_decimals = decimals;
+        state.config.token_asset = new_token;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Whitelists an extra deposit token. `rate` is the amount of `token` equal to one unit of
+    /// `token_asset`, scaled by `RATE_SCALE` (10_000 = 1:1).
+    pub fn add_accepted_token(env: Env, owner: Address, token: Address, rate: i128) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if rate <= 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        if !state.config.accepted_tokens.contains(&token) {
+            state.config.accepted_tokens.push_back(token.clone());
+        }
+        state.config.token_rates.set(token, rate);
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Routes pooled `token_asset` deposits through a yield adapter between payouts (`None` disables).
+    /// Can't be changed while deposits are parked in the current adapter.
+    pub fn set_yield_contract(env: Env, owner: Address, yield_contract: Option<Address>) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if state.yield_principal != 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.yield_contract = yield_contract;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets the dispute window for two-phase execution. A non-zero window replaces
+    /// `execute_cycle` with `begin_cycle` followed by `finalize_cycle`.
+    pub fn set_dispute_window(env: Env, owner: Address, dispute_window_secs: u64) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if env.storage().instance().has(&DataKey::PendingCycle) {
+            return Err(Error::CyclePending);
+        }
+
+        state.config.dispute_window_secs = dispute_window_secs;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets the protocol fee (in basis points) taken from each payout and who receives it.
+    pub fn set_fee(env: Env, owner: Address, fee_bps: u32, fee_recipient: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if fee_bps > MAX_BPS {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.fee_bps = fee_bps;
+        state.config.fee_recipient = fee_recipient;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets how much reputation a successful deposit earns and a missed deposit costs.
+    pub fn set_reputation_deltas(env: Env, owner: Address, rep_gain: u32, rep_loss: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if rep_gain > MAX_REPUTATION_DELTA || rep_loss > MAX_REPUTATION_DELTA {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.rep_gain = rep_gain;
+        state.config.rep_loss = rep_loss;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets the extra reputation a member loses each missed cycle once they have gone more than
+    /// `gap_cycles` cycles without depositing.
+    pub fn set_reputation_decay(env: Env, owner: Address, decay_per_cycle: u32, gap_cycles: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if decay_per_cycle > MAX_REPUTATION_DELTA {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.reputation_decay_per_cycle = decay_per_cycle;
+        state.config.inactivity_gap_cycles = gap_cycles;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Invites an address to join the circle.
+    pub fn add_to_allowlist(env: Env, owner: Address, member: Address) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if !state.config.allowlist.contains(&member) {
+            state.config.allowlist.push_back(member);
+            write_state(&env, &state);
+        }
+        Ok(())
+    }
+
+    /// When enabled, a scheduled recipient who missed this cycle's deposit is skipped in favour
+    /// of the next depositor and keeps their turn for a later cycle.
+    pub fn set_skip_defaulting_recipient(env: Env, owner: Address, skip: bool) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.skip_defaulting_recipient = skip;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets the one-time fee members pay the owner when joining (0 = free).
+    pub fn set_join_fee(env: Env, owner: Address, join_fee: i128) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if join_fee < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.join_fee = join_fee;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets how many member votes pause the circle (0 disables member pausing).
+    pub fn set_pause_quorum(env: Env, owner: Address, pause_quorum: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.pause_quorum = pause_quorum;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets whether the owner may join as a member. Turning it off while the owner is a member
+    /// is rejected; they must leave first.
+    pub fn set_owner_is_member(env: Env, owner: Address, owner_is_member: bool) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if !owner_is_member && get_member_index(&state, &owner).is_ok() {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.owner_is_member = owner_is_member;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// When enabled, the remainder of each penalty split goes to one member, rotating through
+    /// the roster, instead of being carried into the next cycle.
+    pub fn set_round_robin_dust(env: Env, owner: Address, round_robin_dust: bool) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.round_robin_dust = round_robin_dust;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Caps a member's cumulative fine: their balance never drops below `-max_fine` (0 = uncapped).
+    pub fn set_max_fine(env: Env, owner: Address, max_fine: i128) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        if max_fine < 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.max_fine_per_member = max_fine;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets how many deposits a cycle needs before it pays out (0 disables the quorum).
+    pub fn set_min_deposits_for_payout(env: Env, owner: Address, min_deposits: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.min_deposits_for_payout = min_deposits;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets the reputation floor; members who fall below it are banned from depositing, joining,
+    /// and the payout rotation.
+    pub fn set_min_reputation(env: Env, owner: Address, min_reputation: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.min_reputation = min_reputation;
+        write_state(&env, &state);
+        Ok(())
+    }
+
+    /// Sets the reputation score members start with; members who already joined keep their score.
+    pub fn set_starting_reputation(env: Env, owner: Address, starting_reputation: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        state.config.starting_reputation = starting_reputation;
+        write_state(&env, &state);
+        env.storage().instance().set(&DataKey::StartingReputation, &starting_reputation);
+        Ok(())
+    }
+
+    /// Reserves the first `priority_window_secs` of each cycle for members with at least
+    /// `min_reputation`; everyone else can deposit once it ends (0 = no priority window).
+    /// The window must end before the deposit window does.
+    pub fn set_priority_window(env: Env, owner: Address, priority_window_secs: u64, min_reputation: u32) -> Result<(), Error> {
+        let mut state = read_owner_state(&env, &owner)?;
+
+        let deposits_close = if state.config.deposit_window_secs > 0 {
+            state.config.deposit_window_secs
+        } else {
+            state.config.cycle_interval_secs
+        };
+        if priority_window_secs != 0 && priority_window_secs >= deposits_close {
+            return Err(Error::InvalidConfig);
+        }
+
+        state.config.priority_window_secs = priority_window_secs;
+        state.config.priority_min_reputation = min_reputation;
+        write_state(&env, &state);
+        Ok(())
+    }
+
     // --- View Functions (Read-Only) ---
 
+    /// Returns the version of the running contract code.
+    pub fn version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
     pub fn get_circle(env: Env) -> Result<CircleState, Error> {
         if !env.storage().instance().has(&DataKey::CircleState) {
             return Err(Error::NotFound);
@@ -421,8 +2889,315 @@ impl SavingsCircle {
         Ok(read_state(&env))
     }
 
+    /// Returns just the circle's configuration, without the member lists and per-cycle maps.
+    pub fn get_config(env: Env) -> Result<CircleConfig, Error> {
+        if !env.storage().instance().has(&DataKey::CircleState) {
+            return Err(Error::NotFound);
+        }
+        Ok(read_state(&env).config)
+    }
+
+    /// Returns whether `member` holds a deposit receipt for `cycle` of the current season, so
+    /// other contracts can verify a deposit. Receipts outlive membership.
+    pub fn get_receipt(env: Env, member: Address, cycle: u32) -> Result<bool, Error> {
+        if !env.storage().instance().has(&DataKey::CircleState) {
+            return Err(Error::NotFound);
+        }
+        let season = read_state(&env).season;
+        Ok(env.storage().persistent().has(&DataKey::Receipt(member, season, cycle)))
+    }
+
+    /// Returns the circle owner.
+    pub fn get_owner(env: Env) -> Result<Address, Error> {
+        if !env.storage().instance().has(&DataKey::CircleState) {
+            return Err(Error::NotFound);
+        }
+        Ok(read_state(&env).config.owner)
+    }
+
+    /// Returns the amount each member deposits per cycle.
+    pub fn deposit_amount(env: Env) -> i128 {
+        read_state(&env).config.deposit_amount
+    }
+
+    /// Returns the minimum time between cycles, in seconds.
+    pub fn cycle_interval(env: Env) -> u64 {
+        read_state(&env).config.cycle_interval_secs
+    }
+
+    /// Lightweight summary for list pages:
+    /// (current_cycle, member_count, deposit_amount, is_paused, is_open_for_joining).
+    pub fn get_summary(env: Env) -> Result<(u32, u32, i128, bool, bool), Error> {
+        if !env.storage().instance().has(&DataKey::CircleState) {
+            return Err(Error::NotFound);
+        }
+        let state = read_state(&env);
+        Ok((
+            state.current_cycle,
+            member_count(&state),
+            state.config.deposit_amount,
+            state.is_paused,
+            state.is_open_for_joining,
+        ))
+    }
+
     pub fn get_member_state(env: Env, member: Address) -> Result<MemberState, Error> {
-        let state = read_member_state(&env, &member);
-        Ok(state)
+        read_member_state(&env, &member)
+    }
+
+    /// Returns whether `addr` has joined the circle.
+    pub fn is_member(env: Env, addr: Address) -> bool {
+        get_member_index(&read_state(&env), &addr).is_ok()
+    }
+
+    /// Returns the member's slot, which is also their bit in `deposits_bitmap`. Slots don't
+    /// shift when other members leave.
+    pub fn member_index(env: Env, member: Address) -> Result<u32, Error> {
+        let state = read_state(&env);
+        get_member_index(&state, &member)
+    }
+
+    /// Returns the total deposited so far this cycle, valued in `token_asset`.
+    pub fn get_total_deposited_this_cycle(env: Env) -> i128 {
+        read_state(&env).current_cycle_deposited
+    }
+
+    /// Returns whether the member's deposit for the current cycle has been recorded.
+    pub fn has_deposited(env: Env, member: Address) -> Result<bool, Error> {
+        let state = read_state(&env);
+        let member_index = get_member_index(&state, &member)?;
+        Ok(state.deposits_bitmap & member_bit(member_index) != 0)
+    }
+
+    /// Returns the member's accrued balance: positive is claimable, negative is a fine owed.
+    pub fn get_claimable(env: Env, member: Address) -> Result<i128, Error> {
+        Ok(read_member_state(&env, &member)?.penalties_accrued)
+    }
+
+    /// Returns whether `claim_refund` would pay the member out: they have a positive balance
+    /// and the contract holds enough `token_asset` beyond this cycle's deposits and other
+    /// members' backed funds to cover it.
+    pub fn can_claim(env: Env, member: Address) -> Result<bool, Error> {
+        let amount = read_member_state(&env, &member)?.penalties_accrued;
+        if amount <= 0 {
+            return Ok(false);
+        }
+        claim_covered(&env, &read_state(&env), amount)
+    }
+
+    /// Returns the stored record of an executed cycle in the current season.
+    pub fn get_cycle_log(env: Env, cycle: u32) -> Result<CycleRecord, Error> {
+        if !env.storage().instance().has(&DataKey::CircleState) {
+            return Err(Error::NotFound);
+        }
+        Self::get_season_cycle_log(env.clone(), read_state(&env).season, cycle)
+    }
+
+    /// Returns the stored record of an executed cycle from any season (0 = before the first restart).
+    pub fn get_season_cycle_log(env: Env, season: u32, cycle: u32) -> Result<CycleRecord, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CycleLog(season, cycle))
+            .ok_or(Error::NotFound)
+    }
+
+    /// Returns lifetime totals: net pot paid out, fines collected, and cycles executed.
+    pub fn get_stats(env: Env) -> CircleStats {
+        read_stats(&env)
+    }
+
+    /// Returns the total of all positive member balances: penalty (and yield) value credited
+    /// to members and not yet claimed.
+    pub fn get_penalty_pool(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::PenaltyPoolTotal).unwrap_or(0)
+    }
+
+    /// Sums the current members' balances for reconciliation, returning (total owed to
+    /// members, total owed by members). The second value is zero or negative.
+    pub fn total_accrued(env: Env) -> Result<(i128, i128), Error> {
+        let state = read_state(&env);
+        let mut owed_to_members: i128 = 0;
+        let mut owed_by_members: i128 = 0;
+        for member in active_members(&env, &state).iter() {
+            let accrued = read_member_state(&env, &member)?.penalties_accrued;
+            if accrued > 0 {
+                owed_to_members = owed_to_members.saturating_add(accrued);
+            } else {
+                owed_by_members = owed_by_members.saturating_add(accrued);
+            }
+        }
+        Ok((owed_to_members, owed_by_members))
+    }
+
+    /// Returns the member's contact handle, if they set one.
+    pub fn get_contact(env: Env, member: Address) -> Option<Bytes> {
+        env.storage().persistent().get(&DataKey::MemberContact(member))
+    }
+
+    /// Returns the nonce the member's next `deposit_signed` must use.
+    pub fn get_deposit_nonce(env: Env, member: Address) -> u64 {
+        env.storage().persistent().get(&DataKey::DepositNonce(member)).unwrap_or(0)
+    }
+
+    /// Returns the cycle awaiting `finalize_cycle`, if any.
+    pub fn get_pending_cycle(env: Env) -> Option<PendingCycle> {
+        env.storage().instance().get(&DataKey::PendingCycle)
+    }
+
+    /// Returns the circle's lifecycle stage, derived from its flags and current cycle.
+    pub fn status(env: Env) -> CircleStatus {
+        let state = read_state(&env);
+        if state.is_finished {
+            CircleStatus::Finished
+        } else if state.is_paused || state.deposits_paused {
+            CircleStatus::Paused
+        } else if state.current_cycle == 1 {
+            CircleStatus::Forming
+        } else {
+            CircleStatus::Active
+        }
+    }
+
+    /// Keeper hook: returns true when a call to `execute_cycle` would run the cycle now.
+    pub fn is_executable(env: Env) -> bool {
+        let state = read_state(&env);
+        state.config.dispute_window_secs == 0
+            && check_cycle_due(&env, &state).is_ok()
+            && state.deposits_bitmap.count_ones() >= state.config.min_deposits_for_payout
+    }
+
+    /// Returns (members deposited, total members, seconds until executable, can execute now)
+    /// for monitoring dashboards.
+    pub fn health(env: Env) -> (u32, u32, u64, bool) {
+        let state = read_state(&env);
+        let deposited = state.deposits_bitmap.count_ones();
+        let can_execute = check_cycle_due(&env, &state).is_ok()
+            && deposited >= state.config.min_deposits_for_payout;
+        (deposited, member_count(&state), secs_until_executable(&env, &state), can_execute)
+    }
+
+    /// Returns 0 if `execute_cycle` can run now, otherwise the seconds left until it can.
+    pub fn seconds_until_executable(env: Env) -> u64 {
+        let state = read_state(&env);
+        secs_until_executable(&env, &state)
+    }
+
+    /// Returns the absolute timestamp from which the current cycle can be executed.
+    /// Before the first execution this is the end of the first-cycle delay (0 if none is set).
+    pub fn cycle_deadline(env: Env) -> u64 {
+        let state = read_state(&env);
+        let last_cycle_time: u64 = env.storage().instance().get(&DataKey::LastCycleTime).unwrap_or(0);
+        if last_cycle_time == 0 {
+            if state.config.first_cycle_delay_secs == 0 {
+                return 0;
+            }
+            return state.joining_opened_at.saturating_add(state.config.first_cycle_delay_secs);
+        }
+        last_cycle_time.saturating_add(state.config.cycle_interval_secs)
+    }
+
+    /// Returns the 1-based rotation round the current cycle falls in.
+    pub fn round_number(env: Env) -> u32 {
+        let state = read_state(&env);
+        let num_members = member_count(&state);
+        if num_members == 0 {
+            return 1;
+        }
+        (state.current_cycle - 1) / num_members + 1
+    }
+
+    /// Returns how many cycles make up one rotation round (one payout per member).
+    pub fn cycles_per_round(env: Env) -> u32 {
+        member_count(&read_state(&env))
+    }
+
+    /// Returns the remaining payouts of the current round as (cycle, recipient) pairs.
+    /// A random order that hasn't been drawn yet is shown in join order.
+    pub fn get_schedule(env: Env) -> Result<Vec<(u32, Address)>, Error> {
+        let state = read_state(&env);
+        remaining_schedule(&env, &state)
+    }
+
+    /// Returns the next cycle in which `member` is due to receive the payout. Members already
+    /// paid this round get their slot in the next round. Under a random order the turn is
+    /// unknown until the order is drawn, so this fails with `OrderNotDrawn` before the draw and
+    /// for turns that fall in the next round. Banned members have no turn.
+    pub fn turn_cycle(env: Env, member: Address) -> Result<u32, Error> {
+        let state = read_state(&env);
+        let member_index = get_member_index(&state, &member)?;
+        if read_member_state(&env, &member)?.banned {
+            return Err(Error::MemberBanned);
+        }
+
+        let random = state.config.payout_order == PayoutOrder::Random;
+        if random && state.payout_sequence.len() != state.slots.len() {
+            return Err(Error::OrderNotDrawn);
+        }
+        let schedule = remaining_schedule(&env, &state)?;
+        for (cycle, recipient) in schedule.iter() {
+            if recipient == member {
+                return Ok(cycle);
+            }
+        }
+
+        if random {
+            return Err(Error::OrderNotDrawn);
+        }
+
+        // Next round restarts the rotation from position 0, passing over empty slots and
+        // banned members
+        let mask = round_mask(&env, &state)?;
+        let mut turns_before: u32 = 0;
+        for p in 0..member_index {
+            if mask & member_bit(p) != 0 {
+                turns_before = turns_before.checked_add(1).ok_or(Error::Overflow)?;
+            }
+        }
+        state.current_cycle
+            .checked_add(schedule.len())
+            .and_then(|cycle| cycle.checked_add(turns_before))
+            .ok_or(Error::Overflow)
+    }
+
+    /// Returns every member with their reputation score, highest first (ties keep join order).
+    pub fn get_leaderboard(env: Env) -> Result<Vec<(Address, u32)>, Error> {
+        let state = read_state(&env);
+
+        let mut board: Vec<(Address, u32)> = Vec::new(&env);
+        for member in active_members(&env, &state).iter() {
+            let score = read_member_state(&env, &member)?.reputation_score;
+            board.push_back((member, score));
+        }
+
+        // In-place insertion sort, descending by score (no std sort in Soroban)
+        for i in 1..board.len() {
+            let entry = board.get(i).unwrap();
+            let mut j = i;
+            while j > 0 && board.get(j - 1).unwrap().1 < entry.1 {
+                board.set(j, board.get(j - 1).unwrap());
+                j -= 1;
+            }
+            board.set(j, entry);
+        }
+        Ok(board)
+    }
+
+    /// Returns up to `limit` members starting at `start`. `limit` is clamped to `MAX_PAGE_SIZE`.
+    pub fn get_members_page(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let members = active_members(&env, &read_state(&env));
+        let (start, end) = page_bounds(&members, start, limit);
+        members.slice(start..end)
+    }
+
+    /// Returns the member states matching `get_members_page` for the same `start` and `limit`.
+    pub fn get_member_states_page(env: Env, start: u32, limit: u32) -> Result<Vec<MemberState>, Error> {
+        let members = active_members(&env, &read_state(&env));
+        let (start, end) = page_bounds(&members, start, limit);
+
+        let mut page = Vec::new(&env);
+        for member in members.slice(start..end).iter() {
+            page.push_back(read_member_state(&env, &member)?);
+        }
+        Ok(page)
     }
 }
\ No newline at end of file
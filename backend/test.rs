@@ -2,9 +2,16 @@
 
 use super::*;
 use soroban_sdk::{testenvironment::TestEnvironment, vec, Address, Env};
+use soroban_sdk::{testutils::Events, IntoVal};
 
 // --- Test Setup Helper ---
 fn setup_env<'a>() -> (Env, SavingsCircleClient<'a>, Address, Address, Vec<Address>, TokenClient<'a>) {
+    // A large sum in the contract's own balance in case it needs to pay out a penalty refund
+    setup_env_with_contract_funds(1_000_000_000_000)
+}
+
+// Like `setup_env`, with `contract_funds` minted to the contract instead
+fn setup_env_with_contract_funds<'a>(contract_funds: i128) -> (Env, SavingsCircleClient<'a>, Address, Address, Vec<Address>, TokenClient<'a>) {
     let env = Env::default();
     env.ledger().set_timestamp(1_000_000_000); // Set initial time
 
@@ -27,8 +34,9 @@ fn setup_env<'a>() -> (Env, SavingsCircleClient<'a>, Address, Address, Vec<Addre
         // the depositor must have an authorization set, which is automatically handled in testutils.
     }
     
-    // Also mint a large sum to the contract address's admin in case the contract needs to pay out a penalty refund
-    token_client.mint(&token_admin, &client.address, &1_000_000_000_000); 
+    if contract_funds > 0 {
+        token_client.mint(&token_admin, &client.address, &contract_funds);
+    }
 
     (env, client, admin, token_id, members, token_client)
 }
@@ -63,9 +71,8 @@ fn test_create_and_join_circle() {
         let m_state = client.get_member_state(&member).unwrap();
         assert_eq!(m_state.reputation_score, 10);
     }
-    
-    let state = client.get_circle().unwrap();
-    assert_eq!(state.members.len(), initial_members.len());
+
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE), initial_members);
 }
 
 
@@ -88,7 +95,7 @@ fn test_deposit_and_payout_happy_path() {
 
     // --- Cycle 1: All members deposit ---
     for member in members.iter() {
-        client.deposit(&member).unwrap();
+        client.deposit(&member, &token_id).unwrap();
     }
     assert_eq!(token_client.balance(&contract_addr), total_pot);
     
@@ -129,8 +136,8 @@ fn test_execute_cycle_with_missing_deposit_and_claim() {
     let initial_balance_miss = token_client.balance(&missing_member);
     
     // Deposits made
-    client.deposit(&depositor_1).unwrap();
-    client.deposit(&depositor_2).unwrap();
+    client.deposit(&depositor_1, &token_id).unwrap();
+    client.deposit(&depositor_2, &token_id).unwrap();
 
     let collected_deposits = deposit.checked_mul(2).unwrap();
     assert_eq!(token_client.balance(&client.address), collected_deposits);
@@ -168,7 +175,7 @@ fn test_execute_cycle_with_missing_deposit_and_claim() {
     // --- Check Claim Refund Logic ---
     
     // Missing Member cannot claim refund (owes fine)
-    client.claim_refund(&missing_member).unwrap();
+    assert_eq!(client.try_claim_refund(&missing_member), Err(Ok(Error::FineOwed)));
     let m_state_after_claim = client.get_member_state(&missing_member).unwrap();
     assert_eq!(m_state_after_claim.penalties_accrued, -penalty_value); // Still owes -2000
 
@@ -180,4 +187,3263 @@ fn test_execute_cycle_with_missing_deposit_and_claim() {
     assert_eq!(balance_d1_after_claim, balance_d1_before_claim + penalty_share); // +666
     let d_state_after_claim = client.get_member_state(&depositor_1).unwrap();
     assert_eq!(d_state_after_claim.penalties_accrued, 0); // Claimed
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_paid_member_excused_from_deposit() {
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    for excuse in [true, false] {
+        let (env, client, admin, token_id, members, _) = setup_env();
+        client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+        for member in members.iter() { client.join_circle(&member).unwrap(); }
+        client.set_excuse_paid_members(&admin, &excuse).unwrap();
+
+        // --- Cycle 1: everyone deposits, member 0 receives the pot ---
+        for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+
+        let paid_member = members.get(0).unwrap();
+        assert!(client.get_member_state(&paid_member).unwrap().received_payout);
+
+        // --- Cycle 2: the paid member does not deposit ---
+        if excuse {
+            assert_eq!(client.try_deposit(&paid_member, &token_id), Err(Ok(Error::DepositNotRequired)));
+        }
+        client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+        client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+
+        let m_state = client.get_member_state(&paid_member).unwrap();
+        if excuse {
+            // Not required, so not penalized
+            assert_eq!(m_state.reputation_score, 11);
+            assert!(m_state.penalties_accrued >= 0);
+        } else {
+            // Still required, so the miss is penalized
+            assert_eq!(m_state.reputation_score, 10);
+            assert!(m_state.penalties_accrued < 0);
+        }
+    }
+}
+
+
+#[test]
+fn test_payout_fee() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 10000;
+    let cycle_interval: u64 = 100;
+    let fee_recipient = Address::random(&env);
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Fees above 100% are rejected
+    assert_eq!(client.try_set_fee(&admin, &10_001, &fee_recipient), Err(Ok(Error::InvalidConfig)));
+    client.set_fee(&admin, &100, &fee_recipient).unwrap(); // 1%
+
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    let recipient = members.get(0).unwrap();
+    let balance_before = token_client.balance(&recipient);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    let total_pot = deposit.checked_mul(members.len() as i128).unwrap();
+    let fee = total_pot / 100;
+    assert_eq!(token_client.balance(&fee_recipient), fee);
+    assert_eq!(token_client.balance(&recipient), balance_before + total_pot - fee);
+}
+
+
+#[test]
+fn test_members_pagination() {
+    let (env, client, admin, token_id, _, _) = setup_env();
+
+    let mut members = Vec::new(&env);
+    for _ in 0..40 {
+        members.push_back(Address::random(&env));
+    }
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let first = client.get_members_page(&0, &20);
+    let second = client.get_members_page(&20, &20);
+    assert_eq!(first.len(), 20);
+    assert_eq!(second.len(), 20);
+
+    let mut roster = first.clone();
+    roster.append(&second);
+    assert_eq!(roster, members);
+
+    // Oversized pages are clamped, and pages past the end are empty
+    assert_eq!(client.get_members_page(&0, &100).len(), 25);
+    assert_eq!(client.get_members_page(&40, &10).len(), 0);
+
+    let states = client.get_member_states_page(&20, &20).unwrap();
+    assert_eq!(states.len(), 20);
+    assert_eq!(states.get(0).unwrap().reputation_score, 10);
+}
+
+
+#[test]
+fn test_reputation_floor_bans_member() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_min_reputation(&admin, &8).unwrap();
+
+    let defaulter = members.get(1).unwrap();
+
+    // Miss three cycles in a row: 10 -> 9 -> 8 -> 7
+    for _ in 0..3 {
+        client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+        client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    let m_state = client.get_member_state(&defaulter).unwrap();
+    assert_eq!(m_state.reputation_score, 7);
+    assert!(m_state.banned);
+
+    assert_eq!(client.try_deposit(&defaulter, &token_id), Err(Ok(Error::MemberBanned)));
+}
+
+#[test]
+fn test_banned_member_loses_turn() {
+    let (env, client, admin, token_id, members, token_client) = setup_env_with_contract_funds(1_000_000_000_000);
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_min_reputation(&admin, &10).unwrap();
+
+    // The last member misses cycle 1 and drops below the floor
+    let first = members.get(0).unwrap();
+    let banned = members.get(2).unwrap();
+    client.deposit(&first, &token_id).unwrap();
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert!(client.get_member_state(&banned).unwrap().banned);
+
+    let schedule = client.get_schedule().unwrap();
+    assert_eq!(schedule.len(), 1);
+    assert_eq!(schedule.get(0).unwrap(), (2, members.get(1).unwrap()));
+    assert_eq!(client.try_turn_cycle(&banned), Err(Ok(Error::MemberBanned)));
+
+    // Paying member 1 completes the round without the banned member's turn
+    client.deposit(&first, &token_id).unwrap();
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(client.get_circle().unwrap().completed_rounds, 1);
+
+    // Round two opens with member 0, and the banned member is no longer fined
+    let banned_before = client.get_member_state(&banned).unwrap();
+    client.deposit(&first, &token_id).unwrap();
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    let balance_before = token_client.balance(&first);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(token_client.balance(&first), balance_before + 2 * deposit);
+
+    let banned_after = client.get_member_state(&banned).unwrap();
+    assert_eq!(banned_after.missed_count, banned_before.missed_count);
+    assert_eq!(banned_after.penalties_accrued, banned_before.penalties_accrued);
+    assert_eq!(banned_after.reputation_score, banned_before.reputation_score);
+}
+
+
+#[test]
+fn test_relayer_deposits_from_allowance() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Each member pre-authorizes the contract for several cycles worth of deposits
+    let expiration_ledger = env.ledger().sequence() + 1_000;
+    for member in members.iter() {
+        token_client.approve(&member, &client.address, &deposit.checked_mul(5).unwrap(), &expiration_ledger);
+    }
+
+    // A relayer triggers every deposit
+    for member in members.iter() {
+        let balance_before = token_client.balance(&member);
+        client.deposit_from_allowance(&member).unwrap();
+        assert_eq!(token_client.balance(&member), balance_before - deposit);
+    }
+
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.deposits_bitmap, 0b111);
+    assert_eq!(token_client.allowance(&members.get(0).unwrap(), &client.address), deposit.checked_mul(4).unwrap());
+    assert_eq!(client.try_deposit_from_allowance(&members.get(0).unwrap()), Err(Ok(Error::DepositAlreadyMade)));
+}
+
+
+#[test]
+fn test_pause_and_unpause_events() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+
+    client.pause(&admin).unwrap();
+    assert_eq!(
+        env.events().all().last().unwrap(),
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "paused"), admin.clone()).into_val(&env),
+            env.ledger().timestamp().into_val(&env)
+        )
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 50);
+    client.unpause(&admin).unwrap();
+    assert_eq!(
+        env.events().all().last().unwrap(),
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "unpaused"), admin.clone()).into_val(&env),
+            env.ledger().timestamp().into_val(&env)
+        )
+    );
+}
+
+
+#[test]
+fn test_claim_refund_claimable_owed_and_zero() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 10000;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let depositor = members.get(0).unwrap();
+    let missing_member = members.get(1).unwrap();
+
+    // Zero: nothing accrued yet
+    assert_eq!(client.get_claimable(&depositor).unwrap(), 0);
+    assert_eq!(client.try_claim_refund(&depositor), Err(Ok(Error::NothingToClaim)));
+
+    client.deposit(&depositor, &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // Owed: the missing member carries a fine
+    assert!(client.get_claimable(&missing_member).unwrap() < 0);
+    assert_eq!(client.try_claim_refund(&missing_member), Err(Ok(Error::FineOwed)));
+
+    // Claimable: the depositor received a penalty share
+    let claimable = client.get_claimable(&depositor).unwrap();
+    assert!(claimable > 0);
+    let balance_before = token_client.balance(&depositor);
+    client.claim_refund(&depositor).unwrap();
+    assert_eq!(token_client.balance(&depositor), balance_before + claimable);
+    assert_eq!(client.get_claimable(&depositor).unwrap(), 0);
+}
+
+
+#[test]
+fn test_deposit_streak_bonus_and_reset() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let member = members.get(0).unwrap();
+
+    // Five consecutive deposits: +5 reputation, +1 streak bonus
+    for _ in 0..5 {
+        for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    let m_state = client.get_member_state(&member).unwrap();
+    assert_eq!(m_state.current_streak, 5);
+    assert_eq!(m_state.best_streak, 5);
+    assert_eq!(m_state.reputation_score, 16);
+
+    // A missed cycle resets the current streak but keeps the best
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    let m_state = client.get_member_state(&member).unwrap();
+    assert_eq!(m_state.current_streak, 0);
+    assert_eq!(m_state.best_streak, 5);
+}
+
+
+#[test]
+fn test_restart_finished_circle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_max_rounds(&admin, &1).unwrap();
+
+    // Restarting an unfinished circle is rejected
+    assert_eq!(client.try_restart_circle(&admin), Err(Ok(Error::NotFinished)));
+
+    // --- Season 1: one full round ---
+    for _ in 0..members.len() {
+        for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    let state = client.get_circle().unwrap();
+    assert!(state.is_finished);
+    assert_eq!(state.completed_rounds, 1);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::CircleFinished)));
+    assert_eq!(client.try_deposit(&members.get(0).unwrap(), &token_id), Err(Ok(Error::CircleFinished)));
+    let reputation_after_season_1 = client.get_member_state(&members.get(0).unwrap()).unwrap().reputation_score;
+
+    // --- Season 2: restart and run another round ---
+    client.restart_circle(&admin).unwrap();
+    let state = client.get_circle().unwrap();
+    assert!(!state.is_finished);
+    assert!(state.is_open_for_joining);
+    assert_eq!(state.current_cycle, 1);
+    assert_eq!(state.next_payout_index, 0);
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE).len(), members.len());
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().reputation_score, reputation_after_season_1);
+
+    for _ in 0..members.len() {
+        for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+    assert!(client.get_circle().unwrap().is_finished);
+}
+
+
+#[test]
+fn test_seconds_until_executable() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // The first cycle can run right away
+    assert_eq!(client.seconds_until_executable(), 0);
+    client.execute_cycle().unwrap();
+
+    // Right after execution a full interval remains
+    assert_eq!(client.seconds_until_executable(), cycle_interval);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 40);
+    assert_eq!(client.seconds_until_executable(), 60);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 60);
+    assert_eq!(client.seconds_until_executable(), 0);
+    client.execute_cycle().unwrap();
+}
+
+
+#[test]
+fn test_random_payout_order() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+    let num_members = members.len();
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_payout_order(&admin, &PayoutOrder::Random).unwrap();
+
+    let mut balances_before = Vec::new(&env);
+    for member in members.iter() { balances_before.push_back(token_client.balance(&member)); }
+
+    for position in 0..num_members {
+        for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+        let mut balances_mid = Vec::new(&env);
+        for member in members.iter() { balances_mid.push_back(token_client.balance(&member)); }
+
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+
+        // The recipient is the member at this position of the stored sequence
+        let sequence = client.get_circle().unwrap().payout_sequence;
+        let recipient_index = sequence.get(position).unwrap();
+        let recipient = members.get(recipient_index).unwrap();
+        let total_pot = deposit.checked_mul(num_members as i128).unwrap();
+        assert_eq!(token_client.balance(&recipient), balances_mid.get(recipient_index).unwrap() + total_pot);
+    }
+
+    // The stored sequence is a permutation of all member indices
+    let sequence = client.get_circle().unwrap().payout_sequence;
+    assert_eq!(sequence.len(), num_members);
+    for i in 0..num_members {
+        assert!(sequence.contains(&i));
+    }
+
+    // Everyone paid in `num_members` deposits and received the pot exactly once
+    for (i, member) in members.iter().enumerate() {
+        assert_eq!(token_client.balance(&member), balances_before.get(i as u32).unwrap());
+    }
+}
+
+
+#[test]
+fn test_get_summary_matches_state() {
+    let (_env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 100;
+
+    assert_eq!(client.try_get_summary(), Err(Ok(Error::NotFound)));
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.pause(&admin).unwrap();
+
+    let state = client.get_circle().unwrap();
+    let (current_cycle, member_count, deposit_amount, is_paused, is_open_for_joining) = client.get_summary().unwrap();
+    assert_eq!(current_cycle, state.current_cycle);
+    assert_eq!(member_count, client.get_members_page(&0, &MAX_PAGE_SIZE).len());
+    assert_eq!(deposit_amount, state.config.deposit_amount);
+    assert_eq!(is_paused, state.is_paused);
+    assert_eq!(is_open_for_joining, state.is_open_for_joining);
+    assert!(is_paused);
+}
+
+
+#[test]
+fn test_deposit_multiple_whitelisted_tokens() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    // A second stablecoin, accepted at 2 units per unit of the primary token
+    let alt_admin = Address::random(&env);
+    let alt_token_id = env.register_stellar_asset_contract(alt_admin.clone());
+    let alt_token_client = TokenClient::new(&env, &alt_token_id);
+    let alt_depositor = members.get(1).unwrap();
+    alt_token_client.mint(&alt_admin, &alt_depositor, &1_000);
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Non-whitelisted tokens are rejected
+    assert_eq!(client.try_deposit(&alt_depositor, &alt_token_id), Err(Ok(Error::TokenNotAccepted)));
+    client.add_accepted_token(&admin, &alt_token_id, &20_000).unwrap();
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&alt_depositor, &alt_token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+
+    assert_eq!(alt_token_client.balance(&alt_depositor), 1_000 - 2 * deposit);
+    let record = client.get_circle().unwrap().cycle_deposits.get(alt_depositor.clone()).unwrap();
+    assert_eq!(record.token, alt_token_id);
+    assert_eq!(record.amount, 2 * deposit);
+
+    // The recipient is paid in both tokens
+    let recipient = members.get(0).unwrap();
+    let balance_before = token_client.balance(&recipient);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    assert_eq!(token_client.balance(&recipient), balance_before + 2 * deposit);
+    assert_eq!(alt_token_client.balance(&recipient), 2 * deposit);
+    assert_eq!(client.get_circle().unwrap().cycle_deposits.len(), 0);
+}
+
+
+#[test]
+fn test_back_to_back_execution_pays_once() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+
+    // A zero interval means only the idempotency guard stands between two payouts
+    client.create_circle(&admin, &token_id, &deposit, &members, &0, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+
+    let contract_balance_before = token_client.balance(&client.address);
+
+    // Two relayers fire within the same ledger timestamp
+    client.execute_cycle().unwrap();
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::CycleNotReady)));
+
+    let total_pot = deposit.checked_mul(members.len() as i128).unwrap();
+    assert_eq!(token_client.balance(&client.address), contract_balance_before - total_pot);
+
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.current_cycle, 2);
+    assert_eq!(state.last_executed_cycle, 1);
+    assert_eq!(state.next_payout_index, 1);
+}
+
+
+// --- Mock Yield Adapter: returns principal plus 10% on withdraw ---
+mod mock_yield {
+    use soroban_sdk::{contract, contractimpl, contracttype, token::Client as TokenClient, Address, Env};
+
+    #[contracttype]
+    enum Key {
+        Token,
+        Principal,
+    }
+
+    #[contract]
+    pub struct MockYield;
+
+    #[contractimpl]
+    impl MockYield {
+        pub fn init(env: Env, token: Address) {
+            env.storage().instance().set(&Key::Token, &token);
+        }
+
+        pub fn deposit(env: Env, from: Address, amount: i128) {
+            from.require_auth();
+            let principal: i128 = env.storage().instance().get(&Key::Principal).unwrap_or(0);
+            env.storage().instance().set(&Key::Principal, &(principal + amount));
+        }
+
+        pub fn withdraw(env: Env, to: Address) -> i128 {
+            to.require_auth();
+            let token: Address = env.storage().instance().get(&Key::Token).unwrap();
+            let principal: i128 = env.storage().instance().get(&Key::Principal).unwrap_or(0);
+            let amount = principal + principal / 10;
+            TokenClient::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+            env.storage().instance().set(&Key::Principal, &0i128);
+            amount
+        }
+    }
+}
+
+#[test]
+fn test_yield_adapter_surplus_shared() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 1000;
+    let cycle_interval: u64 = 100;
+
+    let yield_id = env.register_contract(None, mock_yield::MockYield);
+    let yield_client = mock_yield::MockYieldClient::new(&env, &yield_id);
+    yield_client.init(&token_id);
+    // Fund the adapter so it can pay the 10% yield
+    token_client.mint(&Address::random(&env), &yield_id, &1_000_000);
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_yield_contract(&admin, &Some(yield_id.clone())).unwrap();
+
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    let total_pot = deposit.checked_mul(members.len() as i128).unwrap();
+    assert_eq!(client.get_circle().unwrap().yield_principal, total_pot);
+    // Parked deposits can't be stranded by switching adapters
+    assert_eq!(client.try_set_yield_contract(&admin, &None), Err(Ok(Error::InvalidConfig)));
+
+    let recipient = members.get(0).unwrap();
+    let balance_before = token_client.balance(&recipient);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // The recipient receives the principal pot; the 10% surplus is shared by the depositors
+    assert_eq!(token_client.balance(&recipient), balance_before + total_pot);
+    let yield_share = (total_pot / 10) / members.len() as i128;
+    for member in members.iter() {
+        assert_eq!(client.get_member_state(&member).unwrap().penalties_accrued, yield_share);
+    }
+    assert_eq!(client.get_circle().unwrap().yield_principal, 0);
+}
+
+
+#[test]
+fn test_set_cycle_interval_applies_to_next_cycle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.execute_cycle().unwrap();
+
+    assert_eq!(client.try_set_cycle_interval(&admin, &0), Err(Ok(Error::InvalidConfig)));
+    client.set_cycle_interval(&admin, &200).unwrap();
+    assert_eq!(client.get_circle().unwrap().config.cycle_interval_secs, 200);
+
+    // The old interval is no longer enough
+    let last_execution = env.ledger().timestamp();
+    env.ledger().set_timestamp(last_execution + 100);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::CycleNotReady)));
+
+    env.ledger().set_timestamp(last_execution + 200);
+    client.execute_cycle().unwrap();
+}
+
+
+#[test]
+fn test_two_phase_cycle_finalizes_after_dispute_window() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+    let dispute_window: u64 = 50;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_dispute_window(&admin, &dispute_window).unwrap();
+
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+
+    // Single-phase execution is disabled while a dispute window is configured
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::DisputeWindowEnabled)));
+
+    client.begin_cycle().unwrap();
+    let pending = client.get_pending_cycle().unwrap();
+    assert_eq!(pending.cycle, 1);
+    assert_eq!(pending.deposits_bitmap, 0b111);
+    assert_eq!(pending.members, members);
+    assert_eq!(client.try_begin_cycle(), Err(Ok(Error::CyclePending)));
+    assert_eq!(client.try_finalize_cycle(), Err(Ok(Error::DisputeWindowOpen)));
+
+    let recipient = members.get(0).unwrap();
+    let balance_before = token_client.balance(&recipient);
+    env.ledger().set_timestamp(env.ledger().timestamp() + dispute_window);
+    client.finalize_cycle().unwrap();
+
+    let total_pot = deposit.checked_mul(members.len() as i128).unwrap();
+    assert_eq!(token_client.balance(&recipient), balance_before + total_pot);
+    assert_eq!(client.get_circle().unwrap().current_cycle, 2);
+    assert!(client.get_pending_cycle().is_none());
+}
+
+#[test]
+fn test_vetoed_cycle_refunds_depositors() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_dispute_window(&admin, &50).unwrap();
+
+    let mut balances_before = Vec::new(&env);
+    for member in members.iter() { balances_before.push_back(token_client.balance(&member)); }
+
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.begin_cycle().unwrap();
+
+    // Deposits are frozen while the cycle is pending
+    assert_eq!(client.try_deposit(&members.get(0).unwrap(), &token_id), Err(Ok(Error::CyclePending)));
+
+    client.veto_cycle(&admin).unwrap();
+
+    for (i, member) in members.iter().enumerate() {
+        assert_eq!(token_client.balance(&member), balances_before.get(i as u32).unwrap());
+    }
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.current_cycle, 1);
+    assert_eq!(state.deposits_bitmap, 0);
+    assert!(client.get_pending_cycle().is_none());
+    assert_eq!(client.try_finalize_cycle(), Err(Ok(Error::NoPendingCycle)));
+}
+
+
+#[test]
+fn test_finalize_rejects_stale_snapshot() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+    let dispute_window: u64 = 50;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_dispute_window(&admin, &dispute_window).unwrap();
+
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.begin_cycle().unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + dispute_window);
+
+    // Circle-level guards apply to the second phase too
+    client.set_deposits_paused(&admin, &true).unwrap();
+    assert_eq!(client.try_finalize_cycle(), Err(Ok(Error::DepositsPaused)));
+    client.set_deposits_paused(&admin, &false).unwrap();
+
+    // A member removed between the phases invalidates the snapshot
+    client.remove_member(&admin, &members.get(2).unwrap()).unwrap();
+    assert_eq!(client.try_finalize_cycle(), Err(Ok(Error::StaleSnapshot)));
+    assert!(client.get_pending_cycle().is_some());
+
+    client.veto_cycle(&admin).unwrap();
+    assert_eq!(client.get_circle().unwrap().current_cycle, 1);
+}
+
+
+#[test]
+fn test_reputation_leaderboard() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let sometimes = members.get(0).unwrap();
+    let never = members.get(1).unwrap();
+    let always = members.get(2).unwrap();
+
+    for cycle in 1..=3 {
+        if cycle < 3 {
+            client.deposit(&sometimes, &token_id).unwrap();
+        }
+        client.deposit(&always, &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    let board = client.get_leaderboard().unwrap();
+    assert_eq!(board.len(), 3);
+    assert_eq!(board.get(0).unwrap(), (always, 13));
+    assert_eq!(board.get(1).unwrap(), (sometimes, 11));
+    assert_eq!(board.get(2).unwrap(), (never, 7));
+}
+
+
+#[test]
+fn test_refund_deposits_after_all_members_left() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let mut balances_before = Vec::new(&env);
+    for member in members.iter() { balances_before.push_back(token_client.balance(&member)); }
+
+    // Two members deposit, then everyone leaves
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    for member in members.iter() { client.leave_circle(&member).unwrap(); }
+
+    let state = client.get_circle().unwrap();
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE).len(), 0);
+    assert_eq!(state.deposits_bitmap, 0);
+    assert_eq!(state.departed_deposits.len(), 2);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::NotFound)));
+
+    client.refund_stuck_deposits(&admin).unwrap();
+    for (i, member) in members.iter().enumerate() {
+        assert_eq!(token_client.balance(&member), balances_before.get(i as u32).unwrap());
+    }
+    assert_eq!(client.try_refund_stuck_deposits(&admin), Err(Ok(Error::NothingToRefund)));
+}
+
+
+#[test]
+fn test_configurable_reputation_deltas() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    assert_eq!(client.try_set_reputation_deltas(&admin, &1, &101), Err(Ok(Error::InvalidConfig)));
+    client.set_reputation_deltas(&admin, &2, &5).unwrap();
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().reputation_score, 12);
+    // A single miss costs 5
+    assert_eq!(client.get_member_state(&members.get(1).unwrap()).unwrap().reputation_score, 5);
+}
+
+
+#[test]
+fn test_sponsor_deposits_for_member() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let sponsor = members.get(0).unwrap();
+    let beneficiary = members.get(1).unwrap();
+    let sponsor_balance = token_client.balance(&sponsor);
+    let beneficiary_balance = token_client.balance(&beneficiary);
+
+    client.deposit_for(&sponsor, &beneficiary).unwrap();
+
+    assert_eq!(token_client.balance(&sponsor), sponsor_balance - deposit);
+    assert_eq!(token_client.balance(&beneficiary), beneficiary_balance);
+    assert_eq!(client.get_member_state(&beneficiary).unwrap().reputation_score, 11);
+    assert_eq!(client.get_member_state(&sponsor).unwrap().reputation_score, 10);
+    assert_eq!(client.get_circle().unwrap().deposits_bitmap, 0b010);
+
+    // The beneficiary's deposit for this cycle is already covered
+    assert_eq!(client.try_deposit(&beneficiary, &token_id), Err(Ok(Error::DepositAlreadyMade)));
+}
+
+
+#[test]
+fn test_huge_pot_returns_overflow() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = i128::MAX / 2;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 100);
+
+    // deposit * 3 members does not fit in an i128
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::Overflow)));
+    assert_eq!(client.get_circle().unwrap().current_cycle, 1);
+}
+
+
+#[test]
+fn test_has_deposited() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let member = members.get(1).unwrap();
+    assert_eq!(client.has_deposited(&member).unwrap(), false);
+    client.deposit(&member, &token_id).unwrap();
+    assert_eq!(client.has_deposited(&member).unwrap(), true);
+    assert_eq!(client.has_deposited(&members.get(0).unwrap()).unwrap(), false);
+
+    assert_eq!(client.try_has_deposited(&Address::random(&env)), Err(Ok(Error::NotMember)));
+}
+
+
+#[test]
+fn test_configured_starting_reputation() {
+    let (_env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    client.set_starting_reputation(&admin, &25).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    for member in members.iter() {
+        assert_eq!(client.get_member_state(&member).unwrap().reputation_score, 25);
+    }
+
+    // Changing the setting later does not rewrite existing members
+    client.set_starting_reputation(&admin, &5).unwrap();
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().reputation_score, 25);
+}
+
+
+#[test]
+fn test_join_requires_invitation() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    client.join_circle(&members.get(0).unwrap()).unwrap();
+
+    let outsider = Address::random(&env);
+    assert_eq!(client.try_join_circle(&outsider), Err(Ok(Error::NotInvited)));
+
+    client.add_to_allowlist(&admin, &outsider).unwrap();
+    client.join_circle(&outsider).unwrap();
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE).len(), 2);
+}
+
+
+#[test]
+fn test_payout_event_carries_pot_breakdown() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 10_000;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_fee(&admin, &100, &admin).unwrap(); // 1%
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    let gross_pot = deposit * 3;
+    let net_payout = gross_pot - gross_pot / 100;
+    let expected = (
+        client.address.clone(),
+        (Symbol::new(&env, "payout"), members.get(0).unwrap(), 1u32).into_val(&env),
+        (1u32, gross_pot, net_payout, 2u32, 7u32).into_val(&env),
+    );
+    assert!(env.events().all().iter().any(|event| event == expected));
+}
+
+
+#[test]
+fn test_member_index_follows_join_order() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    client.join_circle(&members.get(2).unwrap()).unwrap();
+    client.join_circle(&members.get(0).unwrap()).unwrap();
+
+    assert_eq!(client.member_index(&members.get(2).unwrap()).unwrap(), 0);
+    assert_eq!(client.member_index(&members.get(0).unwrap()).unwrap(), 1);
+    assert_eq!(client.try_member_index(&members.get(1).unwrap()), Err(Ok(Error::NotMember)));
+    assert_eq!(client.try_member_index(&Address::random(&env)), Err(Ok(Error::NotMember)));
+}
+
+
+#[test]
+fn test_each_member_paid_once_when_membership_changes_mid_round() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    let mut roster = members.clone();
+    let fourth = Address::random(&env);
+    roster.push_back(fourth.clone());
+    client.create_circle(&admin, &token_id, &100, &roster, &cycle_interval, &1_000).unwrap();
+    for member in roster.iter() { client.join_circle(&member).unwrap(); }
+    client.set_payout_order(&admin, &PayoutOrder::Random).unwrap();
+
+    let mut paid: Vec<Address> = Vec::new(&env);
+    for cycle in 0..3u32 {
+        let mut balances = Vec::new(&env);
+        for member in roster.iter() { balances.push_back(token_client.balance(&member)); }
+
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+
+        for (i, member) in roster.iter().enumerate() {
+            if token_client.balance(&member) > balances.get(i as u32).unwrap() {
+                paid.push_back(member);
+            }
+        }
+
+        // An unpaid member leaves after the first payout, reshuffling the random order
+        if cycle == 0 {
+            let leaver = roster.iter().find(|m| !paid.contains(m)).unwrap();
+            client.leave_circle(&leaver).unwrap();
+            roster.remove(roster.first_index_of(&leaver).unwrap());
+        }
+    }
+
+    // Three payouts went to the three remaining members and closed the round
+    assert_eq!(paid.len(), 3);
+    for member in roster.iter() {
+        assert_eq!(paid.iter().filter(|p| *p == member).count(), 1);
+    }
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.completed_rounds, 1);
+    assert_eq!(state.paid_this_round, 0);
+}
+
+
+#[test]
+fn test_repay_fine_clears_balance_without_overpaying() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 10_000;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Member 1 misses: fined 2000, then gets back a third of the pooled penalty
+    let defaulter = members.get(1).unwrap();
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    let owed = -client.get_claimable(&defaulter).unwrap();
+    assert_eq!(owed, 2000 - 2000 / 3);
+    assert_eq!(client.try_repay_fine(&defaulter, &0), Err(Ok(Error::InvalidAmount)));
+
+    let balance_before = token_client.balance(&defaulter);
+    client.repay_fine(&defaulter, &2000).unwrap();
+
+    // Only the amount owed is pulled, leaving the member at exactly zero
+    assert_eq!(token_client.balance(&defaulter), balance_before - owed);
+    assert_eq!(client.get_claimable(&defaulter).unwrap(), 0);
+    assert_eq!(client.try_repay_fine(&defaulter, &1), Err(Ok(Error::NoFineOwed)));
+}
+
+
+#[test]
+fn test_payout_schedule() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let schedule = client.get_schedule().unwrap();
+    assert_eq!(schedule.len(), members.len());
+    for (i, member) in members.iter().enumerate() {
+        assert_eq!(schedule.get(i as u32).unwrap(), (i as u32 + 1, member));
+    }
+
+    // Paid members drop off the schedule
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    let schedule = client.get_schedule().unwrap();
+    assert_eq!(schedule.len(), 2);
+    assert_eq!(schedule.get(0).unwrap(), (2, members.get(1).unwrap()));
+}
+
+
+#[test]
+fn test_quorum_not_met_holds_the_cycle() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_min_deposits_for_payout(&admin, &2).unwrap();
+
+    let recipient = members.get(0).unwrap();
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    let recipient_balance = token_client.balance(&recipient);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::QuorumNotMet)));
+
+    // No payout, the cycle stays put and the deposit carries forward
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.current_cycle, 1);
+    assert_eq!(state.deposits_bitmap, 0b010);
+    assert_eq!(token_client.balance(&recipient), recipient_balance);
+
+    // A second deposit reaches the quorum
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    client.execute_cycle().unwrap();
+    assert_eq!(client.get_circle().unwrap().current_cycle, 2);
+}
+
+
+#[test]
+fn test_upgrade_requires_owner() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+
+    let wasm_hash = BytesN::from_array(&env, &[0; 32]);
+    assert_eq!(client.try_upgrade(&members.get(0).unwrap(), &wasm_hash), Err(Ok(Error::NotOwner)));
+}
+
+// Release builds for the upgrade test, so it only runs with `--features upgrade-tests`:
+// fixtures/savings_circle_v1.wasm is built from the version 1 release, and the current
+// build comes from `soroban contract build` run before the tests
+#[cfg(feature = "upgrade-tests")]
+mod savings_circle_v1 {
+    soroban_sdk::contractimport!(file = "fixtures/savings_circle_v1.wasm");
+}
+
+#[cfg(feature = "upgrade-tests")]
+mod savings_circle_wasm {
+    soroban_sdk::contractimport!(file = "target/wasm32-unknown-unknown/release/savings_circle_contract.wasm");
+}
+
+#[cfg(feature = "upgrade-tests")]
+#[test]
+fn test_upgrade_keeps_state() {
+    let (env, _, admin, token_id, members, _) = setup_env();
+    let depositor = members.get(0).unwrap();
+
+    // A circle running on the version 1 code
+    let contract_id = env.register_contract_wasm(None, savings_circle_v1::WASM);
+    let old_client = savings_circle_v1::Client::new(&env, &contract_id);
+    old_client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { old_client.join_circle(&member).unwrap(); }
+    old_client.deposit(&depositor, &token_id).unwrap();
+    assert_eq!(old_client.version(), 1);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(savings_circle_wasm::WASM);
+    old_client.upgrade(&admin, &wasm_hash).unwrap();
+
+    // The new code serves the same storage and migrates it forward
+    let client = SavingsCircleClient::new(&env, &contract_id);
+    client.migrate(&admin).unwrap();
+    assert_eq!(client.version(), CONTRACT_VERSION);
+    assert_eq!(client.get_owner().unwrap(), admin);
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE), members);
+    assert!(client.has_deposited(&depositor).unwrap());
+    assert_eq!(client.get_circle().unwrap().current_cycle, 1);
+}
+
+
+#[test]
+fn test_round_number_increments_at_wrap() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.cycles_per_round(), 3);
+
+    // Cycles 1-3 are round 1, cycles 4-6 are round 2
+    let expected_rounds = [1, 1, 1, 2, 2];
+    for (i, expected) in expected_rounds.iter().enumerate() {
+        assert_eq!(client.get_circle().unwrap().current_cycle, i as u32 + 1);
+        assert_eq!(client.round_number(), *expected);
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+}
+
+
+#[test]
+fn test_defaulting_recipient_is_skipped() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_skip_defaulting_recipient(&admin, &true).unwrap();
+
+    // Member 0 is scheduled first but misses the deposit
+    let scheduled = members.get(0).unwrap();
+    let next = members.get(1).unwrap();
+    client.deposit(&next, &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    let scheduled_balance = token_client.balance(&scheduled);
+    let next_balance = token_client.balance(&next);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    assert_eq!(token_client.balance(&scheduled), scheduled_balance);
+    assert_eq!(token_client.balance(&next), next_balance + 300);
+
+    // The skipped member is still owed a payout this round
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.paid_this_round, 0b010);
+    assert!(client.get_schedule().unwrap().iter().any(|(_, member)| member == scheduled));
+}
+
+
+#[test]
+fn test_cycle_log_records_each_cycle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Cycle k has k depositors
+    let mut timestamps = Vec::new(&env);
+    for cycle in 1..=3u32 {
+        for i in 0..cycle { client.deposit(&members.get(i).unwrap(), &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        timestamps.push_back(env.ledger().timestamp());
+        client.execute_cycle().unwrap();
+    }
+
+    for cycle in 1..=3u32 {
+        let record = client.get_cycle_log(&cycle).unwrap();
+        assert_eq!(record.recipient, members.get(cycle - 1).unwrap());
+        assert_eq!(record.pot, deposit * 3);
+        assert_eq!(record.num_deposited, cycle);
+        assert_eq!(record.num_missed, 3 - cycle);
+        assert_eq!(record.timestamp, timestamps.get(cycle - 1).unwrap());
+    }
+    assert_eq!(client.try_get_cycle_log(&4), Err(Ok(Error::NotFound)));
+}
+
+
+#[test]
+fn test_cancel_cycle_refunds_depositors() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_min_deposits_for_payout(&admin, &3).unwrap();
+    assert_eq!(client.try_cancel_cycle(&admin), Err(Ok(Error::NothingToRefund)));
+
+    let first = members.get(0).unwrap();
+    let second = members.get(1).unwrap();
+    let first_balance = token_client.balance(&first);
+    let second_balance = token_client.balance(&second);
+    client.deposit(&first, &token_id).unwrap();
+    client.deposit(&second, &token_id).unwrap();
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::QuorumNotMet)));
+    assert_eq!(client.try_cancel_cycle(&first), Err(Ok(Error::NotOwner)));
+    client.cancel_cycle(&admin).unwrap();
+
+    assert_eq!(token_client.balance(&first), first_balance);
+    assert_eq!(token_client.balance(&second), second_balance);
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.current_cycle, 1);
+    assert_eq!(state.deposits_bitmap, 0);
+}
+
+#[test]
+fn test_cancel_cycle_rolls_back_deposit_credit() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    let member = members.get(1).unwrap();
+    let before = client.get_member_state(&member).unwrap();
+    client.deposit(&member, &token_id).unwrap();
+    assert_eq!(client.get_member_state(&member).unwrap().deposited_count, before.deposited_count + 1);
+
+    client.cancel_cycle(&admin).unwrap();
+
+    let after = client.get_member_state(&member).unwrap();
+    assert_eq!(after.reputation_score, before.reputation_score);
+    assert_eq!(after.deposited_count, before.deposited_count);
+    assert_eq!(after.current_streak, before.current_streak);
+    assert_eq!(after.best_streak, before.best_streak);
+    assert_eq!(after.last_deposit_cycle, before.last_deposit_cycle);
+}
+
+
+// Runs one cycle in which member 1 misses a 10_000 deposit (a 2_000 fine) under `policy`
+fn run_cycle_with_one_miss<'a>(policy: PenaltyPolicy) -> (SavingsCircleClient<'a>, Address, Vec<Address>) {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_penalty_policy(&admin, &policy).unwrap();
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    (client, admin, members)
+}
+
+#[test]
+fn test_penalty_policy_redistribute() {
+    let (client, admin, members) = run_cycle_with_one_miss(PenaltyPolicy::Redistribute);
+
+    assert_eq!(client.get_claimable(&members.get(0).unwrap()).unwrap(), 666);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), -2000 + 666);
+    assert_eq!(client.get_claimable(&admin).unwrap(), 0);
+}
+
+#[test]
+fn test_total_accrued_after_missed_deposit() {
+    let (client, _, _) = run_cycle_with_one_miss(PenaltyPolicy::Redistribute);
+
+    // Members 0 and 2 are each owed 666; member 1 owes the fine less their own share
+    assert_eq!(client.total_accrued().unwrap(), (1_332, -2_000 + 666));
+}
+
+#[test]
+fn test_penalty_policy_to_owner() {
+    let (client, admin, members) = run_cycle_with_one_miss(PenaltyPolicy::ToOwner);
+
+    assert_eq!(client.get_claimable(&admin).unwrap(), 2000);
+    assert_eq!(client.get_claimable(&members.get(0).unwrap()).unwrap(), 0);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), -2000);
+}
+
+#[test]
+fn test_penalty_policy_burn() {
+    let (client, admin, members) = run_cycle_with_one_miss(PenaltyPolicy::Burn);
+
+    assert_eq!(client.get_claimable(&admin).unwrap(), 0);
+    assert_eq!(client.get_claimable(&members.get(0).unwrap()).unwrap(), 0);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), -2000);
+}
+
+
+#[test]
+fn test_thirty_two_members_without_wraparound() {
+    let (env, client, admin, token_id, funded, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    let mut members = funded.clone();
+    while members.len() < 32 {
+        members.push_back(Address::random(&env));
+    }
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Only the member at index 31, the old 32-bit boundary, deposits (funded by a sponsor)
+    let last = members.get(31).unwrap();
+    client.deposit_for(&funded.get(0).unwrap(), &last).unwrap();
+    assert_eq!(client.get_circle().unwrap().deposits_bitmap, 1u64 << 31);
+    assert!(client.has_deposited(&last).unwrap());
+    assert!(!client.has_deposited(&members.get(0).unwrap()).unwrap());
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // Index 31 is credited, index 0 is penalized; neither bit aliased the other
+    assert_eq!(client.get_member_state(&last).unwrap().reputation_score, 11);
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().reputation_score, 9);
+    assert_eq!(client.get_cycle_log(&1).unwrap().num_missed, 31);
+}
+
+#[test]
+fn test_max_members_without_wraparound() {
+    let (env, client, admin, token_id, funded, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    let mut members = funded.clone();
+    while members.len() < 64 {
+        members.push_back(Address::random(&env));
+    }
+    let latecomer = Address::random(&env);
+    let mut invited = members.clone();
+    invited.push_back(latecomer.clone());
+
+    client.create_circle(&admin, &token_id, &100, &invited, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.try_join_circle(&latecomer), Err(Ok(Error::CircleFull)));
+
+    // Only the member at index 63 deposits (funded by a sponsor)
+    let last = members.get(63).unwrap();
+    client.deposit_for(&funded.get(0).unwrap(), &last).unwrap();
+    assert_eq!(client.get_circle().unwrap().deposits_bitmap, 1u64 << 63);
+    assert!(client.has_deposited(&last).unwrap());
+    assert!(!client.has_deposited(&members.get(0).unwrap()).unwrap());
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // Index 63 is credited, index 0 is penalized; neither bit aliased the other
+    assert_eq!(client.get_member_state(&last).unwrap().reputation_score, 11);
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().reputation_score, 9);
+    assert_eq!(client.get_cycle_log(&1).unwrap().num_missed, 63);
+}
+
+
+#[test]
+fn test_set_token_asset_before_first_cycle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    // Created with the wrong token, which the contract holds none of
+    let wrong_token = env.register_stellar_asset_contract(Address::random(&env));
+    client.create_circle(&admin, &wrong_token, &100, &members, &100, &10).unwrap();
+
+    assert_eq!(client.try_set_token_asset(&members.get(0).unwrap(), &token_id), Err(Ok(Error::NotOwner)));
+    client.set_token_asset(&admin, &token_id).unwrap();
+
+    let config = client.get_circle().unwrap().config;
+    assert_eq!(config.token_asset, token_id);
+    assert_eq!(config.accepted_tokens, vec![&env, token_id.clone()]);
+    assert_eq!(config.token_decimals, TokenClient::new(&env, &token_id).decimals());
+
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+}
+
+
+#[test]
+fn test_set_token_asset_rejected_mid_circle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    let other_token = env.register_stellar_asset_contract(Address::random(&env));
+    assert_eq!(client.try_set_token_asset(&admin, &other_token), Err(Ok(Error::CannotChangeToken)));
+    assert_eq!(client.get_circle().unwrap().config.token_asset, token_id);
+}
+
+
+#[test]
+fn test_owner_joins_many_members() {
+    let (env, client, admin, token_id, _, _) = setup_env();
+
+    let mut invited = Vec::new(&env);
+    for _ in 0..5 {
+        invited.push_back(Address::random(&env));
+    }
+    client.create_circle(&admin, &token_id, &100, &invited, &100, &10).unwrap();
+    client.join_circle(&invited.get(0).unwrap()).unwrap();
+
+    assert_eq!(client.try_join_many(&invited.get(1).unwrap(), &invited), Err(Ok(Error::NotOwner)));
+    client.join_many(&admin, &invited).unwrap();
+
+    // The already-joined member is skipped rather than added twice
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE), invited);
+    assert_eq!(client.get_member_state(&invited.get(4).unwrap()).unwrap().reputation_score, 10);
+
+    let outsiders = vec![&env, Address::random(&env)];
+    assert_eq!(client.try_join_many(&admin, &outsiders), Err(Ok(Error::NotInvited)));
+}
+
+
+#[test]
+fn test_turn_cycle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // The last member in the rotation is paid in cycle 3
+    let last = members.get(2).unwrap();
+    assert_eq!(client.turn_cycle(&last).unwrap(), 3);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(client.turn_cycle(&last).unwrap(), 3);
+
+    // Member 0 was paid in cycle 1, so their next turn opens round two
+    assert_eq!(client.turn_cycle(&members.get(0).unwrap()).unwrap(), 4);
+    assert_eq!(client.try_turn_cycle(&Address::random(&env)), Err(Ok(Error::NotMember)));
+}
+
+#[test]
+fn test_turn_cycle_random_order() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for m in members.iter() { client.join_circle(&m).unwrap(); }
+    client.set_payout_order(&admin, &PayoutOrder::Random).unwrap();
+
+    // Nothing is drawn before the first execution
+    let first = members.get(0).unwrap();
+    assert_eq!(client.try_turn_cycle(&first), Err(Ok(Error::OrderNotDrawn)));
+
+    for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 100);
+    client.execute_cycle().unwrap();
+
+    // Members still waiting this round read their cycle from the drawn order; the member
+    // just paid is due in a round whose order is not known yet
+    let sequence = client.get_circle().unwrap().payout_sequence;
+    let paid = members.get(sequence.get(0).unwrap()).unwrap();
+    assert_eq!(client.try_turn_cycle(&paid), Err(Ok(Error::OrderNotDrawn)));
+    assert_eq!(client.turn_cycle(&members.get(sequence.get(1).unwrap()).unwrap()).unwrap(), 2);
+    assert_eq!(client.turn_cycle(&members.get(sequence.get(2).unwrap()).unwrap()).unwrap(), 3);
+}
+
+#[test]
+fn test_payout_order_locked_mid_round() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for m in members.iter() { client.join_circle(&m).unwrap(); }
+
+    for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 100);
+    client.execute_cycle().unwrap();
+
+    assert_eq!(client.try_set_payout_order(&admin, &PayoutOrder::Random), Err(Ok(Error::RoundInProgress)));
+    assert_eq!(client.get_circle().unwrap().config.payout_order, PayoutOrder::Sequential);
+}
+
+#[test]
+fn test_restart_redraws_random_order() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for m in members.iter() { client.join_circle(&m).unwrap(); }
+    client.set_payout_order(&admin, &PayoutOrder::Random).unwrap();
+    client.set_max_rounds(&admin, &1).unwrap();
+    for _ in 0..members.len() {
+        for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + 100);
+        client.execute_cycle().unwrap();
+    }
+    assert_eq!(client.get_circle().unwrap().payout_sequence.len(), members.len());
+
+    client.restart_circle(&admin).unwrap();
+    assert!(client.get_circle().unwrap().payout_sequence.is_empty());
+}
+
+
+#[test]
+fn test_inactive_member_reputation_decays() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.try_set_reputation_decay(&admin, &101, &2), Err(Ok(Error::InvalidConfig)));
+    client.set_reputation_decay(&admin, &2, &2).unwrap();
+
+    // Member 1 never deposits: -1 per miss, plus 2 once more than two cycles have passed
+    let inactive = members.get(1).unwrap();
+    let expected_scores = [9, 8, 5, 2];
+    for expected in expected_scores.iter() {
+        client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+        client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+        assert_eq!(client.get_member_state(&inactive).unwrap().reputation_score, *expected);
+    }
+}
+
+
+#[test]
+fn test_signed_deposit_rejects_replayed_nonce() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let member = members.get(0).unwrap();
+    let expiration_ledger = env.ledger().sequence() + 1_000;
+    token_client.approve(&member, &client.address, &(deposit * 5), &expiration_ledger);
+    let balance_before = token_client.balance(&member);
+
+    assert_eq!(client.get_deposit_nonce(&member), 0);
+    client.deposit_signed(&member, &0).unwrap();
+    assert_eq!(token_client.balance(&member), balance_before - deposit);
+    assert!(client.has_deposited(&member).unwrap());
+    assert_eq!(client.get_deposit_nonce(&member), 1);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // Replaying the same signed authorization in the next cycle is rejected
+    assert_eq!(client.try_deposit_signed(&member, &0), Err(Ok(Error::InvalidNonce)));
+    client.deposit_signed(&member, &1).unwrap();
+}
+
+#[test]
+fn test_signed_deposit_nonce_cannot_wrap() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let member = members.get(0).unwrap();
+    let expiration_ledger = env.ledger().sequence() + 1_000;
+    token_client.approve(&member, &client.address, &deposit, &expiration_ledger);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::DepositNonce(member.clone()), &u64::MAX);
+    });
+
+    assert_eq!(client.try_deposit_signed(&member, &u64::MAX), Err(Ok(Error::Overflow)));
+    assert!(!client.has_deposited(&member).unwrap());
+}
+
+
+#[test]
+fn test_join_fee_collected_on_join() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let join_fee: i128 = 50;
+
+    let broke = Address::random(&env);
+    let mut invited = members.clone();
+    invited.push_back(broke.clone());
+    client.create_circle(&admin, &token_id, &100, &invited, &100, &10).unwrap();
+
+    assert_eq!(client.try_set_join_fee(&admin, &-1), Err(Ok(Error::InvalidConfig)));
+    client.set_join_fee(&admin, &join_fee).unwrap();
+
+    let member = members.get(0).unwrap();
+    let member_balance = token_client.balance(&member);
+    let owner_balance = token_client.balance(&admin);
+    client.join_circle(&member).unwrap();
+    assert_eq!(token_client.balance(&member), member_balance - join_fee);
+    assert_eq!(token_client.balance(&admin), owner_balance + join_fee);
+
+    // A member who can't cover the fee is turned away without joining
+    assert_eq!(client.try_join_circle(&broke), Err(Ok(Error::InsufficientFunds)));
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE).len(), 1);
+}
+
+
+#[test]
+fn test_total_deposited_this_cycle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.get_total_deposited_this_cycle(), 0);
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    assert_eq!(client.get_total_deposited_this_cycle(), 2 * deposit);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(client.get_total_deposited_this_cycle(), 0);
+}
+
+
+#[test]
+fn test_pot_exceeding_contract_balance_is_rejected() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    // The contract holds 1_000_000_000_000; a 3-member pot at this size needs three times that
+    let deposit: i128 = 1_000_000_000_000;
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let contract_balance = token_client.balance(&client.address);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::InsufficientFunds)));
+
+    // Nothing moved and no penalties were recorded
+    assert_eq!(token_client.balance(&client.address), contract_balance);
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.current_cycle, 1);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), 0);
+}
+
+
+#[test]
+fn test_member_contact_handle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let member = members.get(0).unwrap();
+    assert_eq!(client.get_contact(&member), None);
+
+    let handle = Bytes::from_slice(&env, b"tg:@saver42");
+    client.set_contact(&member, &handle).unwrap();
+    assert_eq!(client.get_contact(&member), Some(handle.clone()));
+
+    assert_eq!(client.try_set_contact(&Address::random(&env), &handle), Err(Ok(Error::NotMember)));
+}
+
+
+#[test]
+fn test_circle_health() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    assert_eq!(client.health(), (2, 3, cycle_interval, false));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    assert_eq!(client.health(), (2, 3, 0, true));
+}
+
+
+#[test]
+fn test_penalty_remainder_rolls_over() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Member 1 misses twice; each 2000 fine is split three ways
+    for _ in 0..2 {
+        client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+        client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    // 2000 = 3 * 666 + 2, then 2002 = 3 * 667 + 1
+    assert_eq!(client.get_claimable(&members.get(0).unwrap()).unwrap(), 666 + 667);
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.penalty_dust, 1);
+
+    // Fines, credits and the carried remainder balance out exactly
+    let mut total: i128 = state.penalty_dust;
+    for member in members.iter() { total += client.get_claimable(&member).unwrap(); }
+    assert_eq!(total, 0);
+}
+
+
+#[test]
+fn test_version() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    assert_eq!(client.version(), CONTRACT_VERSION);
+    assert_eq!(CONTRACT_VERSION, 2);
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    let stored: u32 = env.as_contract(&client.address, || {
+        env.storage().instance().get(&DataKey::Version).unwrap()
+    });
+    assert_eq!(stored, CONTRACT_VERSION);
+}
+
+#[test]
+fn test_migrate_updates_stored_version() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    assert_eq!(client.try_migrate(&admin), Err(Ok(Error::AlreadyMigrated)));
+
+    // Storage left behind by version 1, which never wrote the key
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::Version);
+    });
+    assert_eq!(client.try_migrate(&members.get(0).unwrap()), Err(Ok(Error::NotOwner)));
+    client.migrate(&admin).unwrap();
+
+    let stored: u32 = env.as_contract(&client.address, || {
+        env.storage().instance().get(&DataKey::Version).unwrap()
+    });
+    assert_eq!(stored, CONTRACT_VERSION);
+    assert_eq!(client.try_migrate(&admin), Err(Ok(Error::AlreadyMigrated)));
+}
+
+
+#[test]
+fn test_member_vote_pauses_circle() {
+    let (_env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.try_vote_pause(&members.get(0).unwrap()), Err(Ok(Error::InvalidConfig)));
+    client.set_pause_quorum(&admin, &2).unwrap();
+
+    client.vote_pause(&members.get(0).unwrap()).unwrap();
+    assert_eq!(client.try_vote_pause(&members.get(0).unwrap()), Err(Ok(Error::AlreadyVoted)));
+    assert!(!client.get_circle().unwrap().is_paused);
+
+    client.vote_pause(&members.get(1).unwrap()).unwrap();
+    assert!(client.get_circle().unwrap().is_paused);
+    assert_eq!(client.try_deposit(&members.get(2).unwrap(), &token_id), Err(Ok(Error::Paused)));
+
+    // Unpausing clears the votes
+    client.unpause(&admin).unwrap();
+    assert_eq!(client.get_circle().unwrap().pause_votes.len(), 0);
+}
+
+
+#[test]
+fn test_deposit_window_closes_before_execution() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.try_set_deposit_window(&admin, &cycle_interval), Err(Ok(Error::InvalidConfig)));
+    client.set_deposit_window(&admin, &60).unwrap();
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    let cycle_start = env.ledger().timestamp();
+
+    env.ledger().set_timestamp(cycle_start + 30);
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+
+    env.ledger().set_timestamp(cycle_start + 70);
+    assert_eq!(client.try_deposit(&members.get(1).unwrap(), &token_id), Err(Ok(Error::DepositWindowClosed)));
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::CycleNotReady)));
+
+    env.ledger().set_timestamp(cycle_start + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(client.get_circle().unwrap().current_cycle, 3);
+}
+
+
+#[test]
+fn test_get_config_matches_creation() {
+    let (_env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 250;
+    let cycle_interval: u64 = 3_600;
+
+    assert_eq!(client.try_get_config(), Err(Ok(Error::NotFound)));
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+
+    let config = client.get_config().unwrap();
+    assert_eq!(config.owner, admin);
+    assert_eq!(config.token_asset, token_id);
+    assert_eq!(config.deposit_amount, deposit);
+    assert_eq!(config.cycle_interval_secs, cycle_interval);
+    assert_eq!(config.join_deadline_secs, 10);
+    assert_eq!(config.allowlist, members);
+}
+
+
+#[test]
+fn test_owner_runs_circle_without_membership() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    let mut invited = members.clone();
+    invited.push_back(admin.clone());
+    client.create_circle(&admin, &token_id, &deposit, &invited, &cycle_interval, &10).unwrap();
+    client.set_owner_is_member(&admin, &false).unwrap();
+
+    assert_eq!(client.try_join_circle(&admin), Err(Ok(Error::OwnerCannotJoin)));
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // A full round among the three members never touches the owner
+    let owner_balance = token_client.balance(&admin);
+    for _ in 0..3 {
+        for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+    assert_eq!(token_client.balance(&admin), owner_balance);
+    assert_eq!(client.get_circle().unwrap().completed_rounds, 1);
+    assert_eq!(client.try_member_index(&admin), Err(Ok(Error::NotMember)));
+}
+
+
+mod mock_fee_token {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+    #[contracttype]
+    enum Key {
+        Balance(Address),
+    }
+
+    // Token that burns 1% of every transfer
+    #[contract]
+    pub struct MockFeeToken;
+
+    #[contractimpl]
+    impl MockFeeToken {
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&Key::Balance(to), &(balance + amount));
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage().persistent().get(&Key::Balance(id)).unwrap_or(0)
+        }
+
+        pub fn decimals(_env: Env) -> u32 {
+            6
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let from_balance = Self::balance(env.clone(), from.clone());
+            env.storage().persistent().set(&Key::Balance(from), &(from_balance - amount));
+            let to_balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&Key::Balance(to), &(to_balance + amount - amount / 100));
+        }
+    }
+}
+
+#[test]
+fn test_fee_on_transfer_token_records_net_deposits() {
+    let (env, client, admin, _, members, _) = setup_env();
+    let deposit: i128 = 10_000;
+    let cycle_interval: u64 = 100;
+
+    let fee_token_id = env.register_contract(None, mock_fee_token::MockFeeToken);
+    let fee_token = mock_fee_token::MockFeeTokenClient::new(&env, &fee_token_id);
+    for member in members.iter() { fee_token.mint(&member, &(deposit * 5)); }
+
+    client.create_circle(&admin, &fee_token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    for member in members.iter() { client.deposit(&member, &fee_token_id).unwrap(); }
+
+    // Each deposit lost 1% in transit
+    let net_deposit = deposit - deposit / 100;
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.cycle_deposits.get(members.get(0).unwrap()).unwrap().amount, net_deposit);
+    assert_eq!(client.get_total_deposited_this_cycle(), net_deposit * 3);
+    assert_eq!(fee_token.balance(&client.address), net_deposit * 3);
+
+    // The pot is what the contract actually holds, so the payout doesn't overdraw it
+    let recipient = members.get(0).unwrap();
+    let recipient_balance = fee_token.balance(&recipient);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    let pot = net_deposit * 3;
+    assert_eq!(client.get_cycle_log(&1).unwrap().pot, pot);
+    assert_eq!(fee_token.balance(&client.address), 0);
+    assert_eq!(fee_token.balance(&recipient), recipient_balance + pot - pot / 100);
+}
+
+#[test]
+fn test_fee_on_transfer_token_credits_net_payments() {
+    let (env, client, admin, _, members, _) = setup_env();
+    let deposit: i128 = 10_000;
+
+    let fee_token_id = env.register_contract(None, mock_fee_token::MockFeeToken);
+    let fee_token = mock_fee_token::MockFeeTokenClient::new(&env, &fee_token_id);
+    for member in members.iter() { fee_token.mint(&member, &(deposit * 5)); }
+
+    client.create_circle(&admin, &fee_token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    let member = members.get(0).unwrap();
+
+    // 29_700 arrives for three cycles: two full deposits, the rest to the balance
+    client.prepay(&member, &3).unwrap();
+    let m_state = client.get_member_state(&member).unwrap();
+    assert_eq!(m_state.prepaid_cycles, 2);
+    assert_eq!(m_state.prepaid_balance, 9_700);
+
+    client.fund_balance(&member, &1_000).unwrap();
+    assert_eq!(client.get_member_state(&member).unwrap().prepaid_balance, 9_700 + 990);
+
+    let mut m_state = client.get_member_state(&member).unwrap();
+    m_state.penalties_accrued = -2_000;
+    env.as_contract(&client.address, || {
+        write_member_state(&env, &member, &m_state).unwrap();
+    });
+    client.repay_fine(&member, &2_000).unwrap();
+    assert_eq!(client.get_claimable(&member).unwrap(), -20);
+}
+
+
+#[test]
+fn test_extend_join_deadline_reopens_joining() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    client.join_circle(&members.get(0).unwrap()).unwrap();
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 20);
+    let latecomer = members.get(1).unwrap();
+    assert_eq!(client.try_join_circle(&latecomer), Err(Ok(Error::JoinDeadlinePassed)));
+
+    client.extend_join_deadline(&admin, &100).unwrap();
+    client.join_circle(&latecomer).unwrap();
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE).len(), 2);
+}
+
+
+#[test]
+fn test_deposit_and_miss_counts() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Member 1 deposits in cycles 1 and 3 only
+    let member = members.get(1).unwrap();
+    for cycle in 1..=4u32 {
+        if cycle % 2 == 1 {
+            client.deposit(&member, &token_id).unwrap();
+        }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    let m_state = client.get_member_state(&member).unwrap();
+    assert_eq!(m_state.deposited_count, 2);
+    assert_eq!(m_state.missed_count, 2);
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().missed_count, 4);
+}
+
+
+#[test]
+fn test_fines_plateau_at_cap() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_penalty_policy(&admin, &PenaltyPolicy::Burn).unwrap();
+    assert_eq!(client.try_set_max_fine(&admin, &-1), Err(Ok(Error::InvalidConfig)));
+    client.set_max_fine(&admin, &5_000).unwrap();
+
+    // Member 1 misses every cycle: 2000 per miss until the 5000 cap
+    let defaulter = members.get(1).unwrap();
+    let expected_balances = [-2_000, -4_000, -5_000, -5_000, -5_000];
+    for expected in expected_balances.iter() {
+        client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+        client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+        assert_eq!(client.get_claimable(&defaulter).unwrap(), *expected);
+    }
+}
+
+
+#[test]
+fn test_is_member() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    client.join_circle(&members.get(0).unwrap()).unwrap();
+
+    assert!(client.is_member(&members.get(0).unwrap()));
+    // Invited but not yet joined
+    assert!(!client.is_member(&members.get(1).unwrap()));
+    assert!(!client.is_member(&Address::random(&env)));
+}
+
+
+#[test]
+fn test_leave_and_remove_events() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let leaver = members.get(0).unwrap();
+    client.leave_circle(&leaver).unwrap();
+    assert_eq!(
+        env.events().all().last().unwrap(),
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "left"), leaver.clone()).into_val(&env),
+            1u32.into_val(&env)
+        )
+    );
+
+    let removed = members.get(1).unwrap();
+    assert_eq!(client.try_remove_member(&removed, &removed), Err(Ok(Error::NotOwner)));
+    client.remove_member(&admin, &removed).unwrap();
+    assert_eq!(
+        env.events().all().last().unwrap(),
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "removed"), removed.clone()).into_val(&env),
+            (admin.clone(), 1u32).into_val(&env)
+        )
+    );
+    assert_eq!(client.get_members_page(&0, &MAX_PAGE_SIZE), vec![&env, members.get(2).unwrap()]);
+}
+
+
+#[test]
+fn test_prepaid_cycles_cover_missed_deposits() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let traveller = members.get(2).unwrap();
+    let balance_before = token_client.balance(&traveller);
+    client.prepay(&traveller, &2).unwrap();
+    assert_eq!(token_client.balance(&traveller), balance_before - 2 * deposit);
+
+    // Two cycles without calling `deposit`: no penalties
+    for _ in 0..2 {
+        client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+        client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+    let m_state = client.get_member_state(&traveller).unwrap();
+    assert_eq!(m_state.prepaid_cycles, 0);
+    assert_eq!(m_state.missed_count, 0);
+    assert_eq!(m_state.deposited_count, 2);
+    assert_eq!(client.get_claimable(&traveller).unwrap(), 0);
+
+    // The prepaid balance is used up, so the next miss counts
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(client.get_member_state(&traveller).unwrap().missed_count, 1);
+}
+
+
+#[test]
+fn test_penalty_pool_total() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_penalty_policy(&admin, &PenaltyPolicy::ToOwner).unwrap();
+    assert_eq!(client.get_penalty_pool(), 0);
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // The 2000 fine is pooled; the defaulter's negative balance doesn't offset it
+    assert_eq!(client.get_penalty_pool(), 2_000);
+
+    client.claim_refund(&admin).unwrap();
+    assert_eq!(client.get_penalty_pool(), 0);
+}
+
+
+#[test]
+fn test_round_robin_dust_rotates() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_round_robin_dust(&admin, &true).unwrap();
+
+    // Member 1 misses each cycle: 2000 = 3 * 666 + 2, the 2 going to members 0, 1, 2 in turn
+    for cycle in 0..3u32 {
+        let mut before = Vec::new(&env);
+        for member in members.iter() { before.push_back(client.get_claimable(&member).unwrap()); }
+
+        client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+        client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+
+        for (i, member) in members.iter().enumerate() {
+            let fine = if i == 1 { 2_000 } else { 0 };
+            let dust = if i as u32 == cycle { 2 } else { 0 };
+            assert_eq!(client.get_claimable(&member).unwrap(), before.get(i as u32).unwrap() - fine + 666 + dust);
+        }
+        assert_eq!(client.get_circle().unwrap().penalty_dust, 0);
+    }
+}
+
+
+#[test]
+fn test_claims_work_while_deposits_paused() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    client.set_deposits_paused(&admin, &true).unwrap();
+    assert_eq!(client.try_deposit(&members.get(0).unwrap(), &token_id), Err(Ok(Error::DepositsPaused)));
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::DepositsPaused)));
+
+    // Member 2's share of the penalty can still be claimed
+    let claimant = members.get(2).unwrap();
+    let balance_before = token_client.balance(&claimant);
+    client.claim_refund(&claimant).unwrap();
+    assert_eq!(token_client.balance(&claimant), balance_before + 666);
+
+    client.set_deposits_paused(&admin, &false).unwrap();
+    client.execute_cycle().unwrap();
+}
+
+#[test]
+fn test_strict_creation_requires_member_consent() {
+    use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let args = (admin.clone(), token_id.clone(), 10_000i128, members.clone(), 100u64, 10u64).into_val(&env);
+
+    // Only the owner signs, so the members' consent is missing
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "create_circle_strict",
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_create_circle_strict(&admin, &token_id, &10_000, &members, &100, &10).is_err());
+    assert!(client.get_circle().is_none());
+
+    env.mock_all_auths();
+    client.create_circle_strict(&admin, &token_id, &10_000, &members, &100, &10).unwrap();
+    let signers: Vec<Address> = Vec::from_iter(&env, env.auths().iter().map(|(addr, _)| addr.clone()));
+    for member in members.iter() {
+        assert!(signers.contains(&member));
+    }
+}
+
+#[test]
+fn test_cycle_deadline() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.cycle_deadline(), 0);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.execute_cycle().unwrap();
+    let last_cycle_time: u64 = env.as_contract(&client.address, || {
+        env.storage().instance().get(&DataKey::LastCycleTime).unwrap()
+    });
+    assert_eq!(client.cycle_deadline(), last_cycle_time + cycle_interval);
+    // The deadline is absolute, so it doesn't move as time passes
+    env.ledger().set_timestamp(env.ledger().timestamp() + 40);
+    assert_eq!(client.cycle_deadline(), last_cycle_time + cycle_interval);
+}
+
+#[test]
+fn test_join_rejected_once_cycle_started() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &1_000).unwrap();
+    client.join_circle(&members.get(0).unwrap()).unwrap();
+    client.join_circle(&members.get(1).unwrap()).unwrap();
+    client.execute_cycle().unwrap();
+
+    // Still within the join deadline, but the rotation is already underway
+    let state = client.get_circle().unwrap();
+    assert!(state.is_open_for_joining);
+    assert_eq!(client.try_join_circle(&members.get(2).unwrap()), Err(Ok(Error::CycleAlreadyStarted)));
+}
+
+#[test]
+fn test_departed_member_leaves_empty_slot() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    let leaver = members.get(0).unwrap();
+    let second = members.get(1).unwrap();
+    let third = members.get(2).unwrap();
+    client.leave_circle(&leaver).unwrap();
+
+    // The others keep their slots; slot 0 is left empty
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.slots.get(0).unwrap(), None);
+    assert_eq!(client.member_index(&second).unwrap(), 1);
+    assert_eq!(client.member_index(&third).unwrap(), 2);
+
+    client.deposit(&second, &token_id).unwrap();
+    client.deposit(&third, &token_id).unwrap();
+    assert_eq!(client.get_circle().unwrap().deposits_bitmap, 0b110);
+
+    // The empty slot is passed over: the second member is paid first, then the third
+    let balance_before = token_client.balance(&second);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(token_client.balance(&second), balance_before + 200);
+    assert_eq!(client.get_claimable(&second).unwrap(), 0);
+
+    client.deposit(&second, &token_id).unwrap();
+    client.deposit(&third, &token_id).unwrap();
+    let balance_before = token_client.balance(&third);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(token_client.balance(&third), balance_before + 200);
+
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.completed_rounds, 1);
+    assert_eq!(token_client.balance(&leaver), 500_000_000);
+}
+
+#[test]
+fn test_new_member_fills_empty_slot() {
+    let (_, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    client.join_circle(&members.get(0).unwrap()).unwrap();
+    client.join_circle(&members.get(1).unwrap()).unwrap();
+    client.leave_circle(&members.get(0).unwrap()).unwrap();
+
+    let newcomer = members.get(2).unwrap();
+    client.join_circle(&newcomer).unwrap();
+    assert_eq!(client.member_index(&newcomer).unwrap(), 0);
+    assert_eq!(client.member_index(&members.get(1).unwrap()).unwrap(), 1);
+    assert_eq!(client.get_circle().unwrap().slots.len(), 2);
+}
+
+#[test]
+fn test_simulate_cycle_matches_execution() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+
+    let contract_balance = token_client.balance(&client.address);
+    let (recipient, pot, to_be_penalized) = client.simulate_cycle().unwrap();
+    assert_eq!(to_be_penalized, vec![&env, members.get(1).unwrap()]);
+
+    // Nothing moved during the dry run
+    assert_eq!(token_client.balance(&client.address), contract_balance);
+    assert_eq!(client.get_circle().unwrap().current_cycle, 1);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), 0);
+
+    client.execute_cycle().unwrap();
+    let record = client.get_cycle_log(&1).unwrap();
+    assert_eq!(record.recipient, recipient);
+    assert_eq!(record.pot, pot);
+    assert_eq!(record.num_missed, to_be_penalized.len());
+    assert!(client.get_claimable(&members.get(1).unwrap()).unwrap() < 0);
+}
+
+#[test]
+fn test_opted_out_member_gets_no_penalty_share() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.try_set_penalty_share_opt_out(&admin, &true), Err(Ok(Error::NotMember)));
+    client.set_penalty_share_opt_out(&members.get(0).unwrap(), &true).unwrap();
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // The 2000 fine is split two ways instead of three
+    assert_eq!(client.get_claimable(&members.get(0).unwrap()).unwrap(), 0);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), -2_000 + 1_000);
+    assert_eq!(client.get_claimable(&members.get(2).unwrap()).unwrap(), 1_000);
+    assert_eq!(client.get_circle().unwrap().penalty_dust, 0);
+}
+
+#[test]
+fn test_priority_window_holds_back_low_reputation() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+    let trusted = members.get(0).unwrap();
+    let newcomer = members.get(1).unwrap();
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    client.set_starting_reputation(&admin, &20).unwrap();
+    client.join_circle(&trusted).unwrap();
+    client.set_starting_reputation(&admin, &10).unwrap();
+    client.join_circle(&newcomer).unwrap();
+
+    assert_eq!(client.try_set_priority_window(&admin, &cycle_interval, &15), Err(Ok(Error::InvalidConfig)));
+    client.set_priority_window(&admin, &50, &15).unwrap();
+
+    client.deposit(&trusted, &token_id).unwrap();
+    assert_eq!(client.try_deposit(&newcomer, &token_id), Err(Ok(Error::NotYetOpen)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 50);
+    client.deposit(&newcomer, &token_id).unwrap();
+}
+
+#[test]
+fn test_force_execute_bypasses_schedule() {
+    let (_, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.execute_cycle().unwrap();
+
+    // Straight after a cycle the schedule blocks a relayer, but not the owner
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::CycleNotReady)));
+    assert_eq!(client.try_force_execute(&members.get(0).unwrap()), Err(Ok(Error::NotOwner)));
+    client.force_execute(&admin).unwrap();
+    assert_eq!(client.get_circle().unwrap().current_cycle, 3);
+    assert_eq!(client.get_cycle_log(&2).unwrap().recipient, members.get(1).unwrap());
+
+    client.pause(&admin).unwrap();
+    assert_eq!(client.try_force_execute(&admin), Err(Ok(Error::Paused)));
+}
+
+#[test]
+fn test_final_recipient_receives_bonus_reserve() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &1_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_max_rounds(&admin, &1).unwrap();
+    client.set_bonus_skim(&admin, &1_000).unwrap(); // 10%
+
+    // Each pot of 3000 has 300 skimmed off, except that the last payout takes the reserve
+    for (i, recipient) in members.iter().enumerate() {
+        for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+        let balance_before = token_client.balance(&recipient);
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+
+        let expected = if i == 2 { 3_000 + 600 } else { 2_700 };
+        assert_eq!(token_client.balance(&recipient), balance_before + expected);
+    }
+
+    let state = client.get_circle().unwrap();
+    assert!(state.is_finished);
+    assert_eq!(state.bonus_reserve, 0);
+}
+
+#[test]
+fn test_payout_after_members_drop_below_cursor() {
+    let (env, client, admin, token_id, _, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    let mut members = Vec::new(&env);
+    for _ in 0..4 {
+        members.push_back(Address::random(&env));
+    }
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    for _ in 0..3 {
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+    assert_eq!(client.get_circle().unwrap().next_payout_index, 3);
+
+    // Only the first member is left, fewer than the cursor's position
+    for i in 1..4 {
+        client.remove_member(&admin, &members.get(i).unwrap()).unwrap();
+    }
+    let survivor = members.get(0).unwrap();
+    let balance_before = token_client.balance(&survivor);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    assert_eq!(client.get_cycle_log(&4).unwrap().recipient, survivor);
+    assert!(token_client.balance(&survivor) > balance_before);
+}
+
+#[test]
+fn test_repeated_quorum_failures_pause_circle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_min_deposits_for_payout(&admin, &2).unwrap();
+    client.set_max_failures_before_pause(&admin, &2).unwrap();
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.consecutive_failures, 1);
+    assert_eq!(state.current_cycle, 1);
+    assert!(!state.is_paused);
+
+    // Retrying within the same interval doesn't count again
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::QuorumNotMet)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert!(client.get_circle().unwrap().is_paused);
+    assert_eq!(
+        env.events().all().last().unwrap(),
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "auto_pause"), Symbol::new(&env, "quorum")).into_val(&env),
+            env.ledger().timestamp().into_val(&env)
+        )
+    );
+
+    client.unpause(&admin).unwrap();
+    assert_eq!(client.get_circle().unwrap().consecutive_failures, 0);
+}
+
+#[test]
+fn test_get_owner() {
+    let (_, client, admin, token_id, members, _) = setup_env();
+
+    assert_eq!(client.try_get_owner(), Err(Ok(Error::NotFound)));
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    assert_eq!(client.get_owner().unwrap(), admin);
+}
+
+#[test]
+fn test_deposit_receipts() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+    let depositor = members.get(0).unwrap();
+    let misser = members.get(1).unwrap();
+
+    assert_eq!(client.try_get_receipt(&depositor, &1), Err(Ok(Error::NotFound)));
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    client.deposit(&depositor, &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    assert!(client.get_receipt(&depositor, &1).unwrap());
+    assert!(!client.get_receipt(&depositor, &2).unwrap());
+    assert!(!client.get_receipt(&misser, &1).unwrap());
+}
+
+#[test]
+fn test_v1_member_state_migrates_on_read() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let member = members.get(0).unwrap();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    let old = MemberStateV1 { reputation_score: 42, penalties_accrued: -7, last_deposit_cycle: 3 };
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::MemberRep(member.clone()), &old);
+    });
+
+    let migrated = client.get_member_state(&member).unwrap();
+    assert_eq!(migrated.reputation_score, 42);
+    assert_eq!(migrated.penalties_accrued, -7);
+    assert_eq!(migrated.last_deposit_cycle, 3);
+    assert_eq!(migrated.deposited_count, 0);
+    assert_eq!(migrated.version, MEMBER_STATE_VERSION);
+
+    // The next write stores the entry in the current shape
+    client.join_circle(&member).unwrap();
+    let stored: MemberState = env.as_contract(&client.address, || {
+        env.storage().persistent().get(&DataKey::MemberRep(member.clone())).unwrap()
+    });
+    assert_eq!(stored.version, MEMBER_STATE_VERSION);
+    assert_eq!(stored.reputation_score, 42);
+}
+
+#[test]
+fn test_v3_member_state_keeps_its_fields() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let member = members.get(0).unwrap();
+    let payout_address = members.get(1).unwrap();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    let old = MemberStateV3 {
+        reputation_score: 30,
+        penalties_accrued: 5,
+        last_deposit_cycle: 4,
+        received_payout: true,
+        banned: false,
+        current_streak: 2,
+        best_streak: 6,
+        deposited_count: 9,
+        missed_count: 1,
+        prepaid_cycles: 3,
+        opt_out_penalty_share: true,
+        payout_address: Some(payout_address.clone()),
+        version: 3,
+    };
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::MemberRep(member.clone()), &old);
+    });
+
+    let migrated = client.get_member_state(&member).unwrap();
+    assert_eq!(migrated.best_streak, 6);
+    assert_eq!(migrated.prepaid_cycles, 3);
+    assert!(migrated.opt_out_penalty_share);
+    assert_eq!(migrated.payout_address, Some(payout_address));
+    assert_eq!(migrated.prepaid_balance, 0);
+    assert_eq!(migrated.version, MEMBER_STATE_VERSION);
+}
+
+#[test]
+fn test_unreadable_member_state_is_an_error() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let member = members.get(0).unwrap();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::MemberRep(member.clone()), &7u32);
+    });
+
+    assert_eq!(client.try_get_member_state(&member), Err(Ok(Error::UnreadableState)));
+    assert_eq!(client.try_join_circle(&member), Err(Ok(Error::UnreadableState)));
+}
+
+#[test]
+fn test_penalty_policy_to_recipient() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+    let recipient = members.get(0).unwrap();
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_penalty_policy(&admin, &PenaltyPolicy::ToRecipient).unwrap();
+
+    client.deposit(&recipient, &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    let balance_before = token_client.balance(&recipient);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // The 2_000 fine wasn't paid, so only the 30_000 pot goes out
+    assert_eq!(token_client.balance(&recipient), balance_before + 30_000);
+    assert_eq!(client.get_claimable(&recipient).unwrap(), 0);
+    assert_eq!(client.get_claimable(&members.get(2).unwrap()).unwrap(), 0);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), -2_000);
+
+    // Repayments reach the recipient as they come in
+    client.repay_fine(&members.get(1).unwrap(), &500).unwrap();
+    assert_eq!(client.get_claimable(&recipient).unwrap(), 500);
+    client.repay_fine(&members.get(1).unwrap(), &1_500).unwrap();
+    assert_eq!(client.get_claimable(&recipient).unwrap(), 2_000);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), 0);
+}
+
+#[test]
+fn test_unbacked_credit_neither_blocks_payouts_nor_drains_deposits() {
+    let deposit: i128 = 10_000;
+    // The contract holds only the missed share the nominal pot pays out in the first cycle
+    let (env, client, admin, token_id, members, token_client) = setup_env_with_contract_funds(deposit);
+    let cycle_interval: u64 = 100;
+    let defaulter = members.get(2).unwrap();
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+
+    // Cycle 1: the defaulter misses, and its 2_000 fine is credited 666 each but never paid
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    assert_eq!(token_client.balance(&client.address), 0);
+
+    // Cycle 2: the credit can't be paid from the deposits, which still fund the full pot
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    let claimant = members.get(1).unwrap();
+    assert!(!client.can_claim(&claimant).unwrap());
+    assert_eq!(client.try_claim_refund(&claimant), Err(Ok(Error::InsufficientFunds)));
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // Repaying the fine backs the credit, which can then be claimed
+    client.repay_fine(&defaulter, &2_000).unwrap();
+    assert_eq!(token_client.balance(&client.address), 1_334);
+    client.claim_refund(&claimant).unwrap();
+    client.claim_refund(&members.get(0).unwrap()).unwrap();
+    assert_eq!(token_client.balance(&client.address), 2);
+}
+
+#[test]
+fn test_claim_exceeding_contract_balance_fails() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let member = members.get(0).unwrap();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    client.join_circle(&member).unwrap();
+
+    // Credit more than the contract holds
+    let credit = token_client.balance(&client.address) + 1;
+    let mut m_state = client.get_member_state(&member).unwrap();
+    m_state.penalties_accrued = credit;
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::MemberRep(member.clone()), &m_state);
+    });
+
+    let balance_before = token_client.balance(&member);
+    assert_eq!(client.try_claim_refund(&member), Err(Ok(Error::InsufficientFunds)));
+    assert_eq!(client.get_claimable(&member).unwrap(), credit);
+    assert_eq!(token_client.balance(&member), balance_before);
+}
+
+#[test]
+fn test_status_follows_lifecycle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_max_rounds(&admin, &1).unwrap();
+    assert_eq!(client.status(), CircleStatus::Forming);
+
+    client.execute_cycle().unwrap();
+    assert_eq!(client.status(), CircleStatus::Active);
+
+    client.pause(&admin).unwrap();
+    assert_eq!(client.status(), CircleStatus::Paused);
+    client.unpause(&admin).unwrap();
+    assert_eq!(client.status(), CircleStatus::Active);
+
+    for _ in 1..members.len() {
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+    assert_eq!(client.status(), CircleStatus::Finished);
+
+    client.restart_circle(&admin).unwrap();
+    assert_eq!(client.status(), CircleStatus::Forming);
+}
+
+#[test]
+fn test_fine_collected_from_allowance() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+    let defaulter = members.get(1).unwrap();
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    let expiration_ledger = env.ledger().sequence() + 1_000;
+    token_client.approve(&defaulter, &client.address, &5_000, &expiration_ledger);
+
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    let balance_before = token_client.balance(&defaulter);
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+
+    // The 2000 fine was paid in tokens, so only the defaulter's own share is left on account
+    assert_eq!(token_client.balance(&defaulter), balance_before - 2_000);
+    assert_eq!(token_client.allowance(&defaulter, &client.address), 3_000);
+    assert_eq!(client.get_claimable(&defaulter).unwrap(), 666);
+    assert_eq!(client.get_claimable(&members.get(0).unwrap()).unwrap(), 666);
+}
+
+#[test]
+fn test_reset_member_stats_for_new_season() {
+    let (client, admin, members) = run_cycle_with_one_miss(PenaltyPolicy::Redistribute);
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().reputation_score, 11);
+    assert_eq!(client.get_member_state(&members.get(1).unwrap()).unwrap().reputation_score, 9);
+
+    // Members 0 and 2 must claim their shares first
+    assert_eq!(client.try_reset_member_stats(&admin), Err(Ok(Error::ClaimsOutstanding)));
+    client.claim_refund(&members.get(0).unwrap()).unwrap();
+    client.claim_refund(&members.get(2).unwrap()).unwrap();
+    assert_eq!(client.try_reset_member_stats(&members.get(0).unwrap()), Err(Ok(Error::NotOwner)));
+    client.reset_member_stats(&admin).unwrap();
+
+    for member in members.iter() {
+        let m_state = client.get_member_state(&member).unwrap();
+        assert_eq!(m_state.reputation_score, 10);
+        assert_eq!(m_state.penalties_accrued, 0);
+    }
+}
+
+#[test]
+fn test_token_decimals_stored_at_creation() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    assert_eq!(client.get_config().unwrap().token_decimals, token_client.decimals());
+
+    let fee_token_id = env.register_contract(None, mock_fee_token::MockFeeToken);
+    let other = env.register_contract(None, SavingsCircle);
+    let other_client = SavingsCircleClient::new(&env, &other);
+    other_client.create_circle(&admin, &fee_token_id, &100, &members, &100, &10).unwrap();
+    assert_eq!(other_client.get_config().unwrap().token_decimals, 6);
+}
+
+#[test]
+fn test_can_claim() {
+    let (client, _, members) = run_cycle_with_one_miss(PenaltyPolicy::Redistribute);
+    let env = client.env.clone();
+    let claimant = members.get(0).unwrap();
+
+    assert!(client.can_claim(&claimant).unwrap());
+    assert!(!client.can_claim(&members.get(1).unwrap()).unwrap()); // Owes a fine
+
+    client.claim_refund(&claimant).unwrap();
+    assert!(!client.can_claim(&claimant).unwrap()); // Nothing left
+
+    // A credit larger than the contract's balance can't be paid
+    let token_client = TokenClient::new(&env, &client.get_config().unwrap().token_asset);
+    let mut m_state = client.get_member_state(&claimant).unwrap();
+    m_state.penalties_accrued = token_client.balance(&client.address) + 1;
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&DataKey::MemberRep(claimant.clone()), &m_state);
+    });
+    assert!(!client.can_claim(&claimant).unwrap());
+}
+
+#[test]
+fn test_later_deposit_pays_larger_late_fine() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 1_000;
+    let start = env.ledger().timestamp();
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_late_penalty(&admin, &10, &100).unwrap();
+
+    // On time, 10s late, and 50s late into a 100s grace period (full late fine: 1000)
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(start + 20);
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(start + 60);
+    client.deposit(&members.get(1).unwrap(), &token_id).unwrap();
+
+    env.ledger().set_timestamp(start + cycle_interval);
+    let (_, _, to_be_penalized) = client.simulate_cycle().unwrap();
+    assert_eq!(to_be_penalized, vec![&env, members.get(0).unwrap(), members.get(1).unwrap()]);
+    client.execute_cycle().unwrap();
+
+    // Fines of 100 and 500 are pooled and split three ways (200 each)
+    assert_eq!(client.get_claimable(&members.get(0).unwrap()).unwrap(), -100 + 200);
+    assert_eq!(client.get_claimable(&members.get(1).unwrap()).unwrap(), -500 + 200);
+    assert_eq!(client.get_claimable(&members.get(2).unwrap()).unwrap(), 200);
+    assert_eq!(client.get_cycle_log(&1).unwrap().num_missed, 0);
+}
+
+#[test]
+fn test_is_executable_flips_when_interval_elapses() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.execute_cycle().unwrap();
+    let executed_at = env.ledger().timestamp();
+
+    assert!(!client.is_executable());
+    env.ledger().set_timestamp(executed_at + cycle_interval - 1);
+    assert!(!client.is_executable());
+    env.ledger().set_timestamp(executed_at + cycle_interval);
+    assert!(client.is_executable());
+    client.execute_cycle().unwrap();
+    assert!(!client.is_executable());
+}
+
+#[test]
+fn test_cover_missing_backfills_unpaid_member() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit_amount: i128 = 10_000;
+
+    client.create_circle(&admin, &token_id, &deposit_amount, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+
+    // Fund the owner so they can cover the missing deposit
+    token_client.transfer(&members.get(2).unwrap(), &admin, &deposit_amount);
+    client.cover_missing(&admin).unwrap();
+
+    assert_eq!(token_client.balance(&admin), 0);
+    assert_eq!(client.get_circle().unwrap().deposits_bitmap, 0b111);
+    assert_eq!(
+        env.events().all().last().unwrap(),
+        (
+            client.address.clone(),
+            (Symbol::new(&env, "covered"), members.get(1).unwrap()).into_val(&env),
+            (admin.clone(), 1u32).into_val(&env)
+        )
+    );
+
+    client.execute_cycle().unwrap();
+    assert_eq!(client.get_cycle_log(&1).unwrap().num_missed, 0);
+    assert_eq!(client.get_member_state(&members.get(1).unwrap()).unwrap().reputation_score, 10);
+}
+
+#[test]
+fn test_events_filterable_by_cycle_topic() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &10_000, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    client.execute_cycle().unwrap();
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+
+    // Deposit topics carry the cycle, so an indexer can pick out a single cycle's deposits
+    let deposit_sym = Symbol::new(&env, "deposit");
+    let cycle_deposits = |cycle: u32| {
+        env.events().all().iter().filter(|(_, topics, _)| {
+            let name = Symbol::try_from_val(&env, &topics.get(0).unwrap());
+            let topic_cycle = u32::try_from_val(&env, &topics.get(topics.len() - 1).unwrap());
+            name == Ok(deposit_sym.clone()) && topic_cycle == Ok(cycle)
+        }).count()
+    };
+    assert_eq!(cycle_deposits(1), 1);
+    assert_eq!(cycle_deposits(2), 1);
+
+    // Penalty topics end with the cycle too
+    let missed = (
+        client.address.clone(),
+        (Symbol::new(&env, "penalty"), members.get(1).unwrap(), symbol_short!("missed"), 1u32).into_val(&env),
+    );
+    assert!(env.events().all().iter().any(|(contract, topics, _)| (contract, topics) == missed));
+}
+
+#[test]
+fn test_first_cycle_waits_for_configured_delay() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let delay: u64 = 500;
+    let created_at = env.ledger().timestamp();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_first_cycle_delay(&admin, &delay).unwrap();
+
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::CycleNotReady)));
+    env.ledger().set_timestamp(created_at + delay - 1);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::CycleNotReady)));
+    env.ledger().set_timestamp(created_at + delay);
+    client.execute_cycle().unwrap();
+
+    // Once the circle is running the delay is locked
+    assert_eq!(client.try_set_first_cycle_delay(&admin, &0), Err(Ok(Error::CycleAlreadyStarted)));
+}
+
+#[test]
+fn test_payout_goes_to_member_payout_address() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 10_000;
+    let cold_wallet = Address::random(&env);
+    let recipient = members.get(0).unwrap();
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_payout_address(&recipient, &Some(cold_wallet.clone())).unwrap();
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+
+    let recipient_before = token_client.balance(&recipient);
+    client.execute_cycle().unwrap();
+
+    assert_eq!(token_client.balance(&cold_wallet), deposit * 3);
+    assert_eq!(token_client.balance(&recipient), recipient_before);
+    assert!(client.get_member_state(&recipient).unwrap().received_payout);
+}
+
+#[test]
+fn test_deposit_amount_and_cycle_interval_views_match_config() {
+    let (_, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &2_500, &members, &3_600, &10).unwrap();
+    let config = client.get_circle().unwrap().config;
+    assert_eq!(client.deposit_amount(), 2_500);
+    assert_eq!(client.deposit_amount(), config.deposit_amount);
+    assert_eq!(client.cycle_interval(), 3_600);
+    assert_eq!(client.cycle_interval(), config.cycle_interval_secs);
+}
+
+#[test]
+fn test_create_circle_rejects_non_token_address() {
+    let (env, client, admin, _, members, _) = setup_env();
+    let not_a_token = Address::random(&env);
+
+    assert_eq!(
+        client.try_create_circle(&admin, &not_a_token, &100, &members, &100, &10),
+        Err(Ok(Error::InvalidToken))
+    );
+    assert_eq!(client.try_get_owner(), Err(Ok(Error::NotFound)));
+}
+
+#[test]
+fn test_stats_accumulate_across_cycles() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 10_000;
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    assert_eq!(client.get_stats().total_cycles_executed, 0);
+
+    for cycle in 1..=3u32 {
+        for (i, member) in members.iter().enumerate() {
+            // Member 2 misses the second cycle and is fined 20% of the deposit
+            if !(cycle == 2 && i == 2) {
+                client.deposit(&member, &token_id).unwrap();
+            }
+        }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    let stats = client.get_stats();
+    assert_eq!(stats.total_pot_distributed, deposit * 3 * 3);
+    assert_eq!(stats.total_penalties_collected, 2_000);
+    assert_eq!(stats.total_cycles_executed, 3);
+}
+
+#[test]
+fn test_waitlisted_address_fills_vacancy() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let first_waiting = Address::random(&env);
+    let second_waiting = Address::random(&env);
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.add_to_allowlist(&admin, &first_waiting).unwrap();
+    client.add_to_allowlist(&admin, &second_waiting).unwrap();
+    client.join_waitlist(&first_waiting).unwrap();
+    client.join_waitlist(&second_waiting).unwrap();
+    assert_eq!(client.try_join_waitlist(&first_waiting), Err(Ok(Error::AlreadyJoined)));
+
+    client.remove_member(&admin, &members.get(1).unwrap()).unwrap();
+
+    // The first waitlisted address takes the removed member's slot
+    assert_eq!(
+        client.get_members_page(&0, &MAX_PAGE_SIZE),
+        vec![&env, members.get(0).unwrap(), first_waiting.clone(), members.get(2).unwrap()]
+    );
+    assert_eq!(client.get_circle().unwrap().waitlist, vec![&env, second_waiting]);
+}
+
+#[test]
+fn test_funded_subscriber_auto_deposits_each_cycle() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 10_000;
+    let cycle_interval: u64 = 100;
+    let subscriber = members.get(1).unwrap();
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.fund_balance(&subscriber, &(deposit * 3)).unwrap();
+
+    // The subscriber never calls `deposit`, yet is never counted as missing
+    for cycle in 1..=3u32 {
+        client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+        client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+        assert_eq!(client.get_cycle_log(&cycle).unwrap().num_missed, 0);
+    }
+
+    let m_state = client.get_member_state(&subscriber).unwrap();
+    assert_eq!(m_state.prepaid_balance, 0);
+    assert_eq!(m_state.deposited_count, 3);
+    assert_eq!(client.try_withdraw_balance(&subscriber), Err(Ok(Error::NothingToClaim)));
+}
+
+#[test]
+fn test_waitlist_rejects_ineligible_addresses() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let uninvited = Address::random(&env);
+    let banned = Address::random(&env);
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.add_to_allowlist(&admin, &banned).unwrap();
+    env.as_contract(&client.address, || {
+        let mut m_state = read_member_state(&env, &banned).unwrap();
+        m_state.banned = true;
+        write_member_state(&env, &banned, &m_state).unwrap();
+    });
+
+    assert_eq!(client.try_join_waitlist(&uninvited), Err(Ok(Error::NotInvited)));
+    assert_eq!(client.try_join_waitlist(&banned), Err(Ok(Error::MemberBanned)));
+    assert_eq!(client.try_join_waitlist(&members.get(0).unwrap()), Err(Ok(Error::AlreadyJoined)));
+    assert_eq!(client.get_circle().unwrap().waitlist.len(), 0);
+}
+
+#[test]
+fn test_waitlist_skips_ineligible_and_dedupes_on_join() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let joins_directly = Address::random(&env);
+    let banned_later = Address::random(&env);
+    let next_in_line = Address::random(&env);
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    for member in members.iter() {
+        client.join_waitlist(&member).unwrap();
+    }
+    for address in [&joins_directly, &banned_later, &next_in_line] {
+        client.add_to_allowlist(&admin, address).unwrap();
+    }
+    client.join_waitlist(&joins_directly).unwrap();
+    client.join_waitlist(&banned_later).unwrap();
+    client.join_waitlist(&next_in_line).unwrap();
+
+    // Joining directly takes the address off the waitlist, so it can't get a second slot
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.join_circle(&joins_directly).unwrap();
+    assert_eq!(client.get_circle().unwrap().waitlist, vec![&env, banned_later.clone(), next_in_line.clone()]);
+
+    // A waitlisted address banned in the meantime is passed over
+    env.as_contract(&client.address, || {
+        let mut m_state = read_member_state(&env, &banned_later).unwrap();
+        m_state.banned = true;
+        write_member_state(&env, &banned_later, &m_state).unwrap();
+    });
+    client.remove_member(&admin, &members.get(1).unwrap()).unwrap();
+
+    let roster = client.get_members_page(&0, &MAX_PAGE_SIZE);
+    assert!(roster.contains(&next_in_line));
+    assert!(!roster.contains(&banned_later));
+    assert_eq!(roster.len(), 4);
+    assert_eq!(client.get_circle().unwrap().waitlist.len(), 0);
+}
+
+#[test]
+fn test_paid_member_cannot_leave_mid_round() {
+    let (_, client, admin, token_id, members, _) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    client.create_circle(&admin, &token_id, &100, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    for member in members.iter() { client.deposit(&member, &token_id).unwrap(); }
+    client.execute_cycle().unwrap();
+
+    // Member 0 took the first pot; an unpaid member may still leave
+    assert_eq!(client.try_leave_circle(&members.get(0).unwrap()), Err(Ok(Error::AlreadyPaidOut)));
+    client.leave_circle(&members.get(2).unwrap()).unwrap();
+    assert!(client.is_member(&members.get(0).unwrap()));
+}
+
+#[test]
+fn test_payout_cannot_spend_prepaid_balances() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let cycle_interval: u64 = 100;
+
+    // Nobody can afford this deposit, so the whole pot is the missing members' shortfall.
+    // It exceeds the contract's own funds and only fits by dipping into a prepaid balance.
+    let deposit: i128 = 333_333_433_334;
+    let prepaid: i128 = 400_000_000;
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.fund_balance(&members.get(0).unwrap(), &prepaid).unwrap();
+    assert!(token_client.balance(&client.address) >= deposit * 3);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::InsufficientFunds)));
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().prepaid_balance, prepaid);
+}
+
+#[test]
+fn test_unused_prepaid_cycles_refunded_on_leave() {
+    let (_, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let leaver = members.get(2).unwrap();
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    let balance_before = token_client.balance(&leaver);
+    client.prepay(&leaver, &3).unwrap();
+    assert_eq!(token_client.balance(&leaver), balance_before - 3 * deposit);
+
+    client.leave_circle(&leaver).unwrap();
+    assert_eq!(token_client.balance(&leaver), balance_before);
+    assert_eq!(client.get_member_state(&leaver).unwrap().prepaid_cycles, 0);
+}
+
+#[test]
+fn test_unused_prepaid_cycles_refunded_on_finish() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+    let prepayer = members.get(0).unwrap();
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_max_rounds(&admin, &1).unwrap();
+    client.prepay(&prepayer, &5).unwrap();
+    let balance_after_prepay = token_client.balance(&prepayer);
+
+    // The single round draws three of the five prepaid cycles
+    for _ in 0..members.len() {
+        for m in members.iter().skip(1) { client.deposit(&m, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    assert!(client.get_circle().unwrap().is_finished);
+    assert_eq!(client.get_member_state(&prepayer).unwrap().prepaid_cycles, 0);
+    // Two unused cycles come back on top of the pot the prepayer received
+    assert_eq!(token_client.balance(&prepayer), balance_after_prepay + 3 * deposit + 2 * deposit);
+}
+
+#[test]
+fn test_unused_prepaid_cycles_refunded_on_restart() {
+    let (env, client, admin, token_id, members, token_client) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+    let prepayer = members.get(0).unwrap();
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_max_rounds(&admin, &1).unwrap();
+    for _ in 0..members.len() {
+        for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+
+    // Prepaid cycles left behind by a circle that finished before finishing refunded them
+    env.as_contract(&client.address, || {
+        let mut m_state = read_member_state(&env, &prepayer).unwrap();
+        m_state.prepaid_cycles = 2;
+        write_member_state(&env, &prepayer, &m_state).unwrap();
+        add_prepaid_total(&env, 2 * deposit).unwrap();
+    });
+    let balance_before = token_client.balance(&prepayer);
+
+    client.restart_circle(&admin).unwrap();
+    assert_eq!(client.get_member_state(&prepayer).unwrap().prepaid_cycles, 0);
+    assert_eq!(token_client.balance(&prepayer), balance_before + 2 * deposit);
+}
+
+#[test]
+fn test_cancelled_cycle_refunds_cover_to_owner() {
+    let (_, client, admin, token_id, members, token_client) = setup_env();
+    let deposit_amount: i128 = 10_000;
+    let covered = members.get(1).unwrap();
+
+    client.create_circle(&admin, &token_id, &deposit_amount, &members, &100, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.deposit(&members.get(0).unwrap(), &token_id).unwrap();
+    client.deposit(&members.get(2).unwrap(), &token_id).unwrap();
+    token_client.transfer(&members.get(2).unwrap(), &admin, &deposit_amount);
+    let covered_balance = token_client.balance(&covered);
+
+    client.cover_missing(&admin).unwrap();
+    assert!(client.get_receipt(&covered, &1).unwrap());
+    assert_eq!(token_client.balance(&admin), 0);
+
+    client.cancel_cycle(&admin).unwrap();
+    assert_eq!(token_client.balance(&admin), deposit_amount);
+    assert_eq!(token_client.balance(&covered), covered_balance);
+    assert!(!client.get_receipt(&covered, &1).unwrap());
+}
+
+#[test]
+fn test_restart_starts_a_new_season() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+    let deposit: i128 = 100;
+    let cycle_interval: u64 = 100;
+    let delay: u64 = 500;
+
+    client.create_circle(&admin, &token_id, &deposit, &members, &cycle_interval, &10).unwrap();
+    for member in members.iter() { client.join_circle(&member).unwrap(); }
+    client.set_max_rounds(&admin, &1).unwrap();
+    for _ in 0..members.len() {
+        for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+        env.ledger().set_timestamp(env.ledger().timestamp() + cycle_interval);
+        client.execute_cycle().unwrap();
+    }
+    let season_0_log = client.get_cycle_log(&1).unwrap();
+
+    // Leftover dust and failure counts from the first run
+    env.as_contract(&client.address, || {
+        let mut state = read_state(&env);
+        state.penalty_dust = 3;
+        state.consecutive_failures = 2;
+        write_state(&env, &state);
+    });
+
+    client.restart_circle(&admin).unwrap();
+    let restarted_at = env.ledger().timestamp();
+    let state = client.get_circle().unwrap();
+    assert_eq!(state.season, 1);
+    assert_eq!(state.penalty_dust, 0);
+    assert_eq!(state.consecutive_failures, 0);
+    assert_eq!(client.get_claimable(&members.get(0).unwrap()).unwrap(), 1);
+
+    // The first-cycle delay applies to the new season
+    client.set_first_cycle_delay(&admin, &delay).unwrap();
+    for m in members.iter() { client.deposit(&m, &token_id).unwrap(); }
+    assert_eq!(client.try_execute_cycle(), Err(Ok(Error::CycleNotReady)));
+    env.ledger().set_timestamp(restarted_at + delay);
+    client.execute_cycle().unwrap();
+
+    // Cycle 1 of the new season doesn't overwrite cycle 1 of the first
+    assert_eq!(client.get_season_cycle_log(&0, &1).unwrap(), season_0_log);
+    assert_eq!(client.get_cycle_log(&1).unwrap().timestamp, restarted_at + delay);
+    assert_ne!(season_0_log.timestamp, restarted_at + delay);
+}
+
+#[test]
+fn test_starting_reputation_falls_back_to_config_without_key() {
+    let (env, client, admin, token_id, members, _) = setup_env();
+
+    client.create_circle(&admin, &token_id, &100, &members, &100, &10).unwrap();
+    client.set_starting_reputation(&admin, &25).unwrap();
+
+    // A circle created before the dedicated key existed only has the config value
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&DataKey::StartingReputation);
+        assert_eq!(read_member_state(&env, &members.get(0).unwrap()).unwrap().reputation_score, 25);
+    });
+    client.join_circle(&members.get(0).unwrap()).unwrap();
+    assert_eq!(client.get_member_state(&members.get(0).unwrap()).unwrap().reputation_score, 25);
+}
@@ -132,11 +132,13 @@ export const joinCircle = async (memberPubKey: string) => {
     return submitContractCall("join_circle", args, memberPubKey);
 };
 
-export const deposit = async (depositorPubKey: string) => {
+export const deposit = async (depositorPubKey: string, tokenId: string) => {
     // IMPORTANT: Frontend MUST ensure the user has authorized the contract (via token.approve)
     // to spend the deposit amount on the token asset before calling this.
+    // `tokenId` must be one of the circle's accepted tokens.
     const args: xdr.ScVal[] = [
         toScAddress(depositorPubKey),
+        toScAddress(tokenId),
     ];
     return submitContractCall("deposit", args, depositorPubKey);
 };